@@ -0,0 +1,884 @@
+use aoc_core::parse::{lines, Context, Line};
+use aoc_core::{Answer, ParseError, Solution};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Instruction>;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_instructions(input, ctx)
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        solve_part1(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        solve_part2(instructions).into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    /// The input line it came from, for tracing.
+    pub line: usize,
+    pub direction: Direction,
+    pub distance: u64,
+}
+
+/// One statement of the dial's instruction language.
+///
+/// Besides the puzzle's `L<n>` and `R<n>`, a program can turn with `+n`
+/// (right) and `-n` (left), jump straight to a position with `SET n`, repeat
+/// a block with `REPEAT k { ... }` and comment out the rest of a line with
+/// `#`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Rotate(Rotation),
+    /// Moves to `position` (modulo the dial's size) without turning, so no
+    /// clicks are counted.
+    Set { line: usize, position: u64 },
+    Repeat { line: usize, times: u64, body: Vec<Instruction> },
+}
+
+/// How deeply `REPEAT` blocks may nest. The parser and the instruction tree
+/// both recurse once per level, so this keeps them well within the stack.
+pub const MAX_REPEAT_DEPTH: usize = 100;
+
+/// How many rotations and jumps a program may run once its `REPEAT` blocks
+/// are unrolled. Tracing and sweeping take one step at a time, and the cap
+/// also keeps the click totals well within a `u128`.
+pub const MAX_STEPS: u64 = 1 << 24;
+
+impl Instruction {
+    /// The input line it starts on.
+    pub fn line(&self) -> usize {
+        match self {
+            Instruction::Rotate(rotation) => rotation.line,
+            Instruction::Set { line, .. } | Instruction::Repeat { line, .. } => *line,
+        }
+    }
+}
+
+/// The rotations and jumps `instructions` run, in order, unrolling repeat
+/// blocks as it goes rather than all at once.
+pub fn unroll(instructions: &[Instruction]) -> impl Iterator<Item = &Instruction> {
+    // Each open block, the index of its next instruction and how many more
+    // times it runs after this pass
+    let mut stack = vec![(instructions, 0, 0)];
+    std::iter::from_fn(move || {
+        loop {
+            let (block, next, again) = stack.last_mut()?;
+            let Some(instruction) = block.get(*next) else {
+                if *again == 0 {
+                    stack.pop();
+                } else {
+                    (*next, *again) = (0, *again - 1);
+                }
+                continue;
+            };
+            *next += 1;
+            match instruction {
+                Instruction::Repeat { times, body, .. } => {
+                    if *times > 0 && !body.is_empty() {
+                        stack.push((body, 0, times - 1));
+                    }
+                }
+                _ => return Some(instruction),
+            }
+        }
+    })
+}
+
+pub fn parse_instructions(input: &str, ctx: &mut Context) -> Result<Vec<Instruction>, ParseError> {
+    let mut tokens = Vec::new();
+    for line in lines(Day01::DAY, input) {
+        if ctx.skip_blank(&line)? {
+            continue;
+        }
+        tokens.extend(tokenize(line));
+    }
+
+    let (instructions, _) = Parser { tokens, next: 0, depth: 0 }.block(ctx, None)?;
+    Ok(instructions)
+}
+
+// A word of the instruction language and the line it's on
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    line: Line<'a>,
+    text: &'a str,
+}
+
+// Splits a line into words at whitespace and braces, dropping any comment
+fn tokenize(line: Line<'_>) -> impl Iterator<Item = Token<'_>> {
+    let code = line.text.split('#').next().unwrap();
+    let mut rest = code;
+    std::iter::from_fn(move || {
+        rest = rest.trim_start();
+        let first = rest.chars().next()?;
+        let len = if first == '{' || first == '}' {
+            1
+        } else {
+            rest.find(|c: char| c.is_whitespace() || c == '{' || c == '}').unwrap_or(rest.len())
+        };
+        let (text, after) = rest.split_at(len);
+        rest = after;
+        Some(Token { line, text })
+    })
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    next: usize,
+    // REPEAT blocks open around the next token
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    // Instructions up to the `}` closing `open`, or to the end of the input
+    // at the top level, and how many steps they run. Unbalanced braces can't
+    // be skipped over, so they're errors even in lenient mode, as is running
+    // too long.
+    fn block(&mut self, ctx: &mut Context, open: Option<Token<'a>>) -> Result<(Vec<Instruction>, u64), ParseError> {
+        let mut instructions = Vec::new();
+        let mut steps: u64 = 0;
+
+        while let Some(token) = self.advance() {
+            let (instruction, runs) = match token.text {
+                "}" if open.is_some() => return Ok((instructions, steps)),
+                "}" => return Err(token.line.error_at(token.text, "unexpected '}' without a REPEAT")),
+                "REPEAT" => {
+                    if self.depth == MAX_REPEAT_DEPTH {
+                        return Err(token.line.error_at(
+                            token.text,
+                            format!("REPEAT blocks nest more than {} deep", MAX_REPEAT_DEPTH),
+                        ));
+                    }
+                    // A block with a bad count is dropped whole in lenient mode
+                    let times = self.argument::<u64>(&token, "repeat count");
+                    let times = ctx.recover(times)?;
+                    match self.advance() {
+                        Some(brace) if brace.text == "{" => {}
+                        _ => return Err(token.line.error_at(token.text, "expected '{' after REPEAT and its count")),
+                    }
+                    self.depth += 1;
+                    let (body, body_steps) = self.block(ctx, Some(token))?;
+                    self.depth -= 1;
+                    let runs = times.map_or(0, |times| times.saturating_mul(body_steps));
+                    (times.map(|times| Instruction::Repeat { line: token.line.number, times, body }), runs)
+                }
+                _ => {
+                    let instruction = ctx.recover(self.simple(&token))?;
+                    let runs = instruction.is_some() as u64;
+                    (instruction, runs)
+                }
+            };
+            steps = steps.saturating_add(runs);
+            if steps > MAX_STEPS {
+                return Err(token.line.error_at(token.text, format!("the instructions run more than {} steps", MAX_STEPS)));
+            }
+            instructions.extend(instruction);
+        }
+
+        match open {
+            Some(token) => Err(token.line.error_at(token.text, "REPEAT block is never closed with '}'")),
+            None => Ok((instructions, steps)),
+        }
+    }
+
+    fn simple(&mut self, token: &Token<'a>) -> Result<Instruction, ParseError> {
+        let line = token.line;
+        if token.text == "SET" {
+            let position = self.argument(token, "position")?;
+            return Ok(Instruction::Set { line: line.number, position });
+        }
+
+        let (sign, distance) = token.text.split_at(token.text.chars().next().unwrap().len_utf8());
+        let direction = match sign {
+            "L" | "-" => Direction::Left,
+            "R" | "+" => Direction::Right,
+            _ => {
+                return Err(line.error_at(
+                    token.text,
+                    format!("invalid instruction '{}', expected L, R, +, -, SET or REPEAT", token.text),
+                ));
+            }
+        };
+        // The distance may follow as a separate word, as in `L 68`
+        let distance = if distance.is_empty() {
+            self.argument(token, "distance")?
+        } else {
+            line.parse_at(distance, "distance")?
+        };
+
+        Ok(Instruction::Rotate(Rotation { line: line.number, direction, distance }))
+    }
+
+    // The number following `token` on the same line
+    fn argument<T: FromStr>(&mut self, token: &Token<'a>, what: &str) -> Result<T, ParseError> {
+        match self.tokens.get(self.next) {
+            Some(value) if value.line.number == token.line.number && value.text != "{" && value.text != "}" => {
+                self.next += 1;
+                value.line.parse_at(value.text, what)
+            }
+            _ => Err(token.line.error_at(token.text, format!("missing {} after '{}'", what, token.text))),
+        }
+    }
+
+    fn advance(&mut self) -> Option<Token<'a>> {
+        let token = self.tokens.get(self.next).copied();
+        self.next += token.is_some() as usize;
+        token
+    }
+}
+
+pub fn solve_part1(instructions: &[Instruction]) -> u128 {
+    Dial::default().count(instructions, Count::LandOn)
+}
+
+pub fn solve_part2(instructions: &[Instruction]) -> u128 {
+    Dial::default().count(instructions, Count::PassThrough)
+}
+
+/// Which clicks of a rotation count towards the password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    /// Only where the rotation stops.
+    LandOn,
+    /// Every click, including where the rotation stops.
+    PassThrough,
+}
+
+/// A dial of `size` positions, numbered from 0, that starts at `start` and
+/// counts the clicks that reach any of its targets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: u64,
+    start: u64,
+    // Sorted and without repeats
+    targets: Vec<u64>,
+}
+
+impl Default for Dial {
+    /// The puzzle's dial: 0 to 99, starting at 50, counting hits on 0.
+    fn default() -> Self {
+        Dial { size: 100, start: 50, targets: vec![0] }
+    }
+}
+
+impl Dial {
+    pub fn new(size: u64, start: u64, targets: impl IntoIterator<Item = u64>) -> Result<Self, String> {
+        if size == 0 {
+            return Err("the dial needs at least one position".to_string());
+        }
+        let mut targets: Vec<u64> = targets.into_iter().collect();
+        targets.sort_unstable();
+        targets.dedup();
+        if let Some(&position) = std::iter::once(&start).chain(&targets).find(|&&position| position >= size) {
+            return Err(format!("position {} is not on a dial of size {}", position, size));
+        }
+        Ok(Dial { size, start, targets })
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    pub fn targets(&self) -> &[u64] {
+        &self.targets
+    }
+
+    /// Where the dial ends up after turning `distance` clicks from `position`.
+    pub fn turn(&self, position: u64, direction: Direction, distance: u64) -> u64 {
+        let step = distance % self.size;
+        match direction {
+            Direction::Left => self.add(position, self.size - step),
+            Direction::Right => self.add(position, step),
+        }
+    }
+
+    /// How many of the clicks turning `distance` from `position` land on a
+    /// target, in constant time per target however far the dial turns.
+    pub fn count_passes(&self, position: u64, direction: Direction, distance: u64) -> u128 {
+        self.targets
+            .iter()
+            .map(|&target| {
+                // Clicks until the dial first reaches the target; after that
+                // it's every full turn
+                let gap = match direction {
+                    Direction::Left => self.add(position, self.size - target),
+                    Direction::Right => self.add(target, self.size - position),
+                };
+                let first = if gap == 0 { self.size } else { gap };
+                if distance < first { 0 } else { ((distance - first) / self.size + 1) as u128 }
+            })
+            .sum()
+    }
+
+    // Adds modulo the size, without overflowing on a dial near u64::MAX
+    fn add(&self, a: u64, b: u64) -> u64 {
+        ((a as u128 + b as u128) % self.size as u128) as u64
+    }
+
+    pub fn is_target(&self, position: u64) -> bool {
+        self.targets.binary_search(&position).is_ok()
+    }
+
+    /// Runs `instructions` from the dial's start, one step per rotation or
+    /// jump.
+    pub fn trace<'a>(&'a self, instructions: &'a [Instruction]) -> impl Iterator<Item = Step<'a>> + 'a {
+        unroll(instructions).scan(self.start, |position, instruction| {
+            let from = *position;
+            let step = match *instruction {
+                Instruction::Rotate(Rotation { direction, distance, .. }) => {
+                    *position = self.turn(from, direction, distance);
+                    Step {
+                        instruction,
+                        from,
+                        to: *position,
+                        passes: self.count_passes(from, direction, distance),
+                        landed: self.is_target(*position),
+                    }
+                }
+                Instruction::Set { position: to, .. } => {
+                    *position = to % self.size;
+                    Step { instruction, from, to: *position, passes: 0, landed: false }
+                }
+                Instruction::Repeat { .. } => unreachable!("unroll yields no repeats"),
+            };
+            Some(step)
+        })
+    }
+
+    /// Runs `instructions` from the dial's start and counts the clicks that
+    /// reach a target.
+    pub fn count(&self, instructions: &[Instruction], mode: Count) -> u128 {
+        self.trace(instructions).map(|step| step.hits(mode)).sum()
+    }
+
+    /// Counts for every start position at once, in a single run through
+    /// `instructions` rather than one run per start.
+    pub fn sweep(&self, instructions: &[Instruction]) -> Result<Sweep, String> {
+        if self.size > MAX_SWEEP_SIZE {
+            return Err(format!("a sweep needs a dial of at most {} positions, not {}", MAX_SWEEP_SIZE, self.size));
+        }
+        let size = self.size as usize;
+
+        // Until the first SET, a dial that started at `s` is at `s + offset`,
+        // so each hit belongs to the starts the target is offset from. Full
+        // turns, and everything after a SET, count the same for every start.
+        let mut offset = Some(0);
+        let mut position = 0;
+        let mut land_on = vec![0; size];
+        let mut pass_through = vec![0i128; size + 1];
+        let mut shared = [0u128; 2];
+
+        for instruction in unroll(instructions) {
+            match (instruction, offset) {
+                (&Instruction::Set { position: to, .. }, _) => {
+                    offset = None;
+                    position = to % self.size;
+                }
+                (&Instruction::Rotate(Rotation { direction, distance, .. }), None) => {
+                    shared[1] += self.count_passes(position, direction, distance);
+                    position = self.turn(position, direction, distance);
+                    shared[0] += self.is_target(position) as u128;
+                }
+                (&Instruction::Rotate(Rotation { direction, distance, .. }), Some(from)) => {
+                    let rest = distance % self.size;
+                    shared[1] += (distance / self.size) as u128 * self.targets.len() as u128;
+                    for &target in &self.targets {
+                        // The starts whose last `rest` clicks reach the target
+                        let first = match direction {
+                            Direction::Left => self.add(target, self.size - from + 1),
+                            Direction::Right => self.add(target, 2 * self.size - from - rest),
+                        };
+                        add_cyclic(&mut pass_through, first as usize, rest as usize);
+                    }
+
+                    let to = self.turn(from, direction, distance);
+                    for &target in &self.targets {
+                        land_on[self.add(target, self.size - to) as usize] += 1;
+                    }
+                    offset = Some(to);
+                }
+                (Instruction::Repeat { .. }, _) => unreachable!("unroll yields no repeats"),
+            }
+        }
+
+        let mut running = 0;
+        let pass_through = pass_through[..size]
+            .iter()
+            .map(|&change| {
+                running += change;
+                running as u128 + shared[1]
+            })
+            .collect();
+        let land_on = land_on.into_iter().map(|count| count + shared[0]).collect();
+        Ok(Sweep { land_on, pass_through })
+    }
+}
+
+/// The largest dial `Dial::sweep` will take, as it keeps counts for every
+/// position.
+pub const MAX_SWEEP_SIZE: u64 = 1 << 24;
+
+// Adds 1 to `len` entries of a difference array from `start`, wrapping
+// around the end
+fn add_cyclic(diff: &mut [i128], start: usize, len: usize) {
+    let size = diff.len() - 1;
+    if len == 0 {
+        return;
+    }
+    diff[start] += 1;
+    if start + len <= size {
+        diff[start + len] -= 1;
+    } else {
+        diff[size] -= 1;
+        diff[0] += 1;
+        diff[start + len - size] -= 1;
+    }
+}
+
+/// The counts from every start position of a dial, indexed by start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sweep {
+    pub land_on: Vec<u128>,
+    pub pass_through: Vec<u128>,
+}
+
+impl Sweep {
+    pub fn counts(&self, mode: Count) -> &[u128] {
+        match mode {
+            Count::LandOn => &self.land_on,
+            Count::PassThrough => &self.pass_through,
+        }
+    }
+}
+
+/// How one part's count varies with the start position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    /// The start with the highest count, and that count. Ties go to the
+    /// lowest start.
+    pub best: (u64, u128),
+    /// The start with the lowest count, and that count.
+    pub worst: (u64, u128),
+    pub mean: f64,
+    /// How many starts give each count.
+    pub distribution: BTreeMap<u128, usize>,
+}
+
+/// Summarises counts indexed by start, or `None` if there are none.
+pub fn summarize(counts: &[u128]) -> Option<Summary> {
+    let best = (0..counts.len()).rev().max_by_key(|&start| counts[start])?;
+    let worst = (0..counts.len()).min_by_key(|&start| counts[start])?;
+
+    let mut distribution = BTreeMap::new();
+    for &count in counts {
+        *distribution.entry(count).or_insert(0) += 1;
+    }
+
+    Some(Summary {
+        best: (best as u64, counts[best]),
+        worst: (worst as u64, counts[worst]),
+        mean: counts.iter().map(|&count| count as f64).sum::<f64>() / counts.len() as f64,
+        distribution,
+    })
+}
+
+/// What one rotation or jump did to the dial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub instruction: &'a Instruction,
+    pub from: u64,
+    pub to: u64,
+    /// Clicks that reached a target, including the last.
+    pub passes: u128,
+    /// Whether a rotation stopped on a target.
+    pub landed: bool,
+}
+
+impl Step<'_> {
+    /// What the step adds to the count in `mode`.
+    pub fn hits(&self, mode: Count) -> u128 {
+        match mode {
+            Count::LandOn => self.landed as u128,
+            Count::PassThrough => self.passes,
+        }
+    }
+}
+
+/// How `render_trace` lays out the steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// Aligned columns for reading.
+    Table,
+    /// Comma-separated values with a header row.
+    Csv,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(TraceFormat::Table),
+            "csv" => Ok(TraceFormat::Csv),
+            other => Err(format!("trace format must be table or csv, got '{}'", other)),
+        }
+    }
+}
+
+/// Renders `steps` one line each, after a header.
+pub fn render_trace(steps: &[Step], format: TraceFormat) -> String {
+    const HEADER: [&str; 6] = ["line", "direction", "distance", "from", "to", "crossings"];
+    let rows: Vec<[String; 6]> = steps
+        .iter()
+        .map(|step| {
+            let (direction, distance) = match step.instruction {
+                Instruction::Rotate(rotation) => (rotation.direction.to_string(), rotation.distance.to_string()),
+                _ => ("SET".to_string(), String::new()),
+            };
+            [
+                step.instruction.line().to_string(),
+                direction,
+                distance,
+                step.from.to_string(),
+                step.to.to_string(),
+                step.passes.to_string(),
+            ]
+        })
+        .collect();
+
+    let mut out = String::new();
+    match format {
+        TraceFormat::Csv => {
+            out.push_str(&HEADER.join(","));
+            out.push('\n');
+            for row in &rows {
+                out.push_str(&row.join(","));
+                out.push('\n');
+            }
+        }
+        TraceFormat::Table => {
+            let widths: Vec<usize> = (0..HEADER.len())
+                .map(|i| rows.iter().map(|row| row[i].len()).chain([HEADER[i].len()]).max().unwrap())
+                .collect();
+            let header = HEADER.map(str::to_string);
+            for row in std::iter::once(&header).chain(&rows) {
+                let cells: Vec<String> = row.iter().zip(&widths).map(|(cell, &width)| format!("{:>width$}", cell)).collect();
+                out.push_str(&cells.join("  "));
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    const EXAMPLE: &str = fixture!("example");
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(&Day01::parse(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(&Day01::parse(EXAMPLE).unwrap()), 6);
+    }
+
+    #[test]
+    fn test_count_passes() {
+        let dial = Dial::default();
+        assert_eq!(dial.count_passes(50, Direction::Left, 68), 1);
+        assert_eq!(dial.count_passes(50, Direction::Right, 1000), 10);
+        assert_eq!(dial.count_passes(0, Direction::Left, 5), 0);
+        assert_eq!(dial.count_passes(0, Direction::Left, 100), 1);
+        assert_eq!(dial.count_passes(99, Direction::Right, 1), 1);
+        assert_eq!(dial.count_passes(50, Direction::Right, 4_000_000_000), 40_000_000);
+        assert_eq!(dial.count_passes(1, Direction::Left, u64::MAX), (u64::MAX / 100 + 1) as u128);
+        assert_eq!(dial.turn(1, Direction::Left, u64::MAX), 86);
+    }
+
+    #[test]
+    fn test_custom_dial() {
+        assert!(Dial::new(0, 0, [0]).is_err());
+        assert!(Dial::new(10, 10, [0]).is_err());
+        assert!(Dial::new(10, 0, [3, 12]).is_err());
+
+        // R7 from 2 passes 5 and 7 to end on 9, then L4 passes 7 and ends on 5
+        let dial = Dial::new(10, 2, [7, 5, 5]).unwrap();
+        assert_eq!(dial.targets(), &[5, 7]);
+        let instructions = Day01::parse("R7\nL4").unwrap();
+        assert_eq!(dial.count(&instructions, Count::LandOn), 1);
+        assert_eq!(dial.count(&instructions, Count::PassThrough), 4);
+
+        // Every click of a one-position dial is a hit
+        let dial = Dial::new(1, 0, [0]).unwrap();
+        let instructions = Day01::parse("R18446744073709551615\nL18446744073709551615").unwrap();
+        assert_eq!(dial.count(&instructions, Count::PassThrough), 2 * u64::MAX as u128);
+        assert_eq!(Dial::new(5, 0, []).unwrap().count(&instructions, Count::LandOn), 0);
+
+        let dial = Dial::new(u64::MAX, u64::MAX - 1, [1]).unwrap();
+        assert_eq!(dial.turn(u64::MAX - 1, Direction::Right, 3), 2);
+        assert_eq!(dial.count_passes(u64::MAX - 1, Direction::Right, 3), 1);
+    }
+
+    // Turns the dial one click at a time, as the puzzle describes it
+    fn count_passes_by_clicks(dial: &Dial, mut position: u64, direction: Direction, distance: u64) -> u128 {
+        let mut count = 0;
+        for _ in 0..distance {
+            position = match direction {
+                Direction::Left => (position + dial.size() - 1) % dial.size(),
+                Direction::Right => (position + 1) % dial.size(),
+            };
+            if dial.is_target(position) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // A xorshift generator of numbers below its argument. A fixed seed keeps
+    // the cases reproducible.
+    fn random(seed: u64) -> impl FnMut(u64) -> u64 {
+        let mut state = seed;
+        move |bound| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % bound
+        }
+    }
+
+    #[test]
+    fn test_count_passes_matches_clicks() {
+        let mut random = random(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..10_000 {
+            let size = 1 + random(120);
+            let targets: Vec<u64> = (0..random(4)).map(|_| random(size)).collect();
+            let dial = Dial::new(size, random(size), targets).unwrap();
+
+            let start = random(size);
+            let direction = if random(2) == 0 { Direction::Left } else { Direction::Right };
+            let distance = random(1000);
+            assert_eq!(
+                dial.count_passes(start, direction, distance),
+                count_passes_by_clicks(&dial, start, direction, distance),
+                "{:?} {} from {}",
+                direction,
+                distance,
+                start
+            );
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let instructions = Day01::parse(EXAMPLE).unwrap();
+        let dial = Dial::default();
+        let steps: Vec<Step> = dial.trace(&instructions).collect();
+        assert_eq!(steps.len(), 10);
+        assert_eq!((steps[0].from, steps[0].to, steps[0].passes, steps[0].landed), (50, 82, 1, false));
+        assert_eq!((steps[2].from, steps[2].to, steps[2].passes, steps[2].landed), (52, 0, 1, true));
+        assert!(steps.windows(2).all(|pair| pair[0].to == pair[1].from));
+
+        let csv = render_trace(&steps[..2], TraceFormat::Csv);
+        assert_eq!(csv, "line,direction,distance,from,to,crossings\n1,L,68,50,82,1\n2,L,30,82,52,0\n");
+
+        let table = render_trace(&steps[..2], TraceFormat::Table);
+        assert_eq!(
+            table,
+            "line  direction  distance  from  to  crossings\n   1          L        68    50  82          1\n   2          L        30    82  52          0\n"
+        );
+    }
+
+    fn rotate(line: usize, direction: Direction, distance: u64) -> Instruction {
+        Instruction::Rotate(Rotation { line, direction, distance })
+    }
+
+    #[test]
+    fn test_parse_language() {
+        let input = "# warm up\nSET 10\nREPEAT 2 {\n  +5 -3  # net +2\n  REPEAT 3 { L 1 }\n}\nR7";
+        let instructions = Day01::parse(input).unwrap();
+        assert_eq!(
+            instructions,
+            vec![
+                Instruction::Set { line: 2, position: 10 },
+                Instruction::Repeat {
+                    line: 3,
+                    times: 2,
+                    body: vec![
+                        rotate(4, Direction::Right, 5),
+                        rotate(4, Direction::Left, 3),
+                        Instruction::Repeat { line: 5, times: 3, body: vec![rotate(5, Direction::Left, 1)] },
+                    ],
+                },
+                rotate(7, Direction::Right, 7),
+            ]
+        );
+
+        let lines: Vec<usize> = unroll(&instructions).map(Instruction::line).collect();
+        assert_eq!(lines, vec![2, 4, 4, 5, 5, 5, 4, 4, 5, 5, 5, 7]);
+        let ends: Vec<u64> = Dial::default().trace(&instructions).map(|step| step.to).collect();
+        assert_eq!(ends, vec![10, 15, 12, 11, 10, 9, 14, 11, 10, 9, 8, 15]);
+    }
+
+    #[test]
+    fn test_language_counts() {
+        // SET jumps without counting, even onto a target
+        let instructions = Day01::parse("SET 0\nSET 99\n+1\nREPEAT 1000000 { +100 }\nREPEAT 0 { +50 }").unwrap();
+        assert_eq!(solve_part1(&instructions), 1_000_001);
+        assert_eq!(solve_part2(&instructions), 1_000_001);
+
+        assert_eq!(Dial::default().trace(&Day01::parse("SET 250").unwrap()).next().unwrap().to, 50);
+    }
+
+    #[test]
+    fn test_language_errors() {
+        let err = Day01::parse("REPEAT 2 {\nL5").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.message, "REPEAT block is never closed with '}'");
+
+        let err = Day01::parse("L5\n}").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(Day01::parse("REPEAT 2\nL5").is_err());
+        assert!(Day01::parse("REPEAT x { L5 }").is_err());
+
+        let err = Day01::parse("SET\n5").unwrap_err();
+        assert_eq!(err.message, "missing position after 'SET'");
+
+        // Lenient mode drops a block with a bad count but not a bad brace
+        let mut ctx = Context::lenient();
+        let instructions = parse_instructions("REPEAT x { L5 }\nR3", &mut ctx).unwrap();
+        assert_eq!(instructions, vec![rotate(2, Direction::Right, 3)]);
+        assert!(parse_instructions("L5 }", &mut Context::lenient()).is_err());
+
+        // Too deep to parse without risking the stack, lenient or not
+        let nested = |depth| format!("R1\n{}L1{}", "REPEAT 1 {\n".repeat(depth), " }".repeat(depth));
+        assert_eq!(Day01::parse(&nested(MAX_REPEAT_DEPTH)).map(|instructions| solve_part2(&instructions)), Ok(0));
+        let err = Day01::parse(&nested(200_000)).unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (MAX_REPEAT_DEPTH + 2, 1, 6));
+        assert_eq!(err.message, "REPEAT blocks nest more than 100 deep");
+        assert!(parse_instructions(&nested(200_000), &mut Context::lenient()).is_err());
+
+        // Counts that would run too long to trace, including ones whose
+        // product overflows
+        assert!(Day01::parse("REPEAT 4096 { REPEAT 4095 { R1 } }\nREPEAT 4096 { L1 }").is_ok());
+        let err = Day01::parse("R1\nREPEAT 100000000000 { R1 }").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (2, 1, 6));
+        assert_eq!(err.message, "the instructions run more than 16777216 steps");
+        let err = Day01::parse("REPEAT 18446744073709551615 { REPEAT 18446744073709551615 { R1 } }").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 31));
+        let err = Day01::parse("REPEAT 4096 { REPEAT 4096 { R1 } }\nL1").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_sweep_example() {
+        let instructions = Day01::parse(EXAMPLE).unwrap();
+        let sweep = Dial::default().sweep(&instructions).unwrap();
+        assert_eq!((sweep.land_on[50], sweep.pass_through[50]), (3, 6));
+
+        let summary = summarize(sweep.counts(Count::PassThrough)).unwrap();
+        assert_eq!(summary.distribution.values().sum::<usize>(), 100);
+        assert!(summary.worst.1 <= 6 && 6 <= summary.best.1);
+        assert_eq!(sweep.pass_through[summary.best.0 as usize], summary.best.1);
+
+        assert!(Dial::new(MAX_SWEEP_SIZE + 1, 0, [0]).unwrap().sweep(&instructions).is_err());
+        assert_eq!(summarize(&[]), None);
+    }
+
+    #[test]
+    fn test_sweep_matches_each_start() {
+        let mut random = random(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..200 {
+            let size = 1 + random(30);
+            let targets: Vec<u64> = (0..random(4)).map(|_| random(size)).collect();
+            let dial = Dial::new(size, 0, targets.clone()).unwrap();
+
+            let instructions: Vec<Instruction> = (1..=random(12) as usize)
+                .map(|line| match random(8) {
+                    0 => Instruction::Set { line, position: random(2 * size) },
+                    1 => Instruction::Repeat { line, times: random(3), body: vec![rotate(line, Direction::Left, random(70))] },
+                    n => rotate(line, if n % 2 == 0 { Direction::Left } else { Direction::Right }, random(70)),
+                })
+                .collect();
+
+            let sweep = dial.sweep(&instructions).unwrap();
+            for start in 0..size {
+                let dial = Dial::new(size, start, targets.clone()).unwrap();
+                assert_eq!(sweep.land_on[start as usize], dial.count(&instructions, Count::LandOn));
+                assert_eq!(sweep.pass_through[start as usize], dial.count(&instructions, Count::PassThrough));
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day01::parse("L68\nX30").unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (1, 2, 1));
+        assert_eq!(err.message, "invalid instruction 'X30', expected L, R, +, -, SET or REPEAT");
+
+        let err = Day01::parse("L68\nR4x").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (2, 2, 2));
+        assert_eq!(err.message, "invalid distance '4x'");
+
+        assert!(Day01::parse("L").is_err());
+        assert!(Day01::parse("R-5").is_err());
+        assert_eq!(Day01::parse("R4000000000").unwrap(), vec![rotate(1, Direction::Right, 4_000_000_000)]);
+        // Comment lines hold no instructions, but blank lines are still
+        // errors in strict mode, as for every other day
+        assert_eq!(
+            Day01::parse("L68\n# note\nR48\n").unwrap(),
+            vec![rotate(1, Direction::Left, 68), rotate(3, Direction::Right, 48)]
+        );
+        let err = Day01::parse("L68\n\nR48").unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (2, "unexpected blank line"));
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let mut ctx = Context::lenient();
+        let instructions = parse_instructions("L68\n\nX30\nR48\n", &mut ctx).unwrap();
+        assert_eq!(instructions.len(), 2);
+
+        let skipped: Vec<_> = ctx.skipped().iter().map(|err| err.line).collect();
+        assert_eq!(skipped, vec![2, 3]);
+    }
+}
@@ -1,90 +1,117 @@
-use std::fs;
+use aoc_core::cli::{self, Format, InputArgs};
+use day_01::{render_trace, summarize, Count, Day01, Dial, Instruction, TraceFormat};
+use std::process;
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Failed to read input file");
-    let password_part1 = solve_part1(&input);
-    println!("Part 1 Password: {}", password_part1);
-
-    let password_part2 = solve_part2(&input);
-    println!("Part 2 Password: {}", password_part2);
-}
-
-fn solve_part1(input: &str) -> i32 {
-    let mut position: i32 = 50;
-    let mut zero_count: i32 = 0;
+const USAGE: &str = "[--size N] [--start N (default 50 mod size)] [--targets N,...] [--trace table|csv] [--until N (instructions, a REPEAT block counting as one)] [--sweep]";
 
-    for line in input.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
+fn main() {
+    let mut size = None;
+    let mut start = None;
+    let mut targets = None;
+    let mut trace = None;
+    let mut until = None;
+    let mut sweep = false;
+    let args = InputArgs::from_env_with(USAGE, |arg, rest| {
+        let mut value = || rest.next().ok_or(format!("missing value for {}", arg));
+        match arg {
+            "--size" => size = Some(parse_number(arg, &value()?)?),
+            "--start" => start = Some(parse_number(arg, &value()?)?),
+            "--targets" => {
+                let list = value()?;
+                targets = Some(list.split(',').map(|target| parse_number(arg, target)).collect::<Result<Vec<_>, _>>()?);
+            }
+            "--trace" => trace = Some(value()?.parse()?),
+            "--until" => until = Some(parse_number(arg, &value()?)?),
+            "--sweep" => sweep = true,
+            _ => return Ok(false),
         }
+        Ok(true)
+    });
 
-        let direction = line.chars().next().unwrap();
-        let distance: i32 = line[1..].trim().parse().expect("Invalid distance");
-
-        position = match direction {
-            'L' => (position - distance).rem_euclid(100),
-            'R' => (position + distance) % 100,
-            _ => panic!("Invalid direction: {}", direction),
-        };
+    let puzzle = Dial::default();
+    let custom = size.is_some() || start.is_some() || targets.is_some() || trace.is_some() || until.is_some() || sweep;
+    if sweep && (start.is_some() || trace.is_some() || until.is_some()) {
+        eprintln!("error: --sweep tries every start over the whole input, so it can't take --start, --trace or --until");
+        process::exit(2);
+    }
+    // On a smaller dial the puzzle's start wraps round, so --size works
+    // alone. A sweep tries every start, so its dial's own start doesn't matter.
+    let size = size.unwrap_or(puzzle.size());
+    let default_start = if sweep { 0 } else { puzzle.start().checked_rem(size).unwrap_or(0) };
+    let dial = Dial::new(
+        size,
+        start.unwrap_or(default_start),
+        targets.unwrap_or_else(|| puzzle.targets().to_vec()),
+    )
+    .unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        process::exit(2);
+    });
 
-        if position == 0 {
-            zero_count += 1;
+    if args.format == Format::Json {
+        if custom {
+            eprintln!("error: --format json only supports the puzzle's dial and a full run");
+            process::exit(2);
         }
+        return cli::print_json::<Day01>(&args);
     }
-    zero_count
-}
 
-fn solve_part2(input: &str) -> i32 {
-    let mut position: i32 = 50;
-    let mut zero_count: i32 = 0;
+    let instructions = cli::load::<Day01>(&args);
+    if sweep {
+        return print_sweep(&dial, &instructions);
+    }
 
-    for line in input.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
+    // Replay only the first N instructions as written, whole REPEAT blocks
+    // included, once, keeping the steps only for the trace
+    let limit = until.map_or(instructions.len(), |until| instructions.len().min(until.try_into().unwrap_or(usize::MAX)));
+    let mut traced = Vec::new();
+    let (mut password_part1, mut password_part2) = (0u128, 0u128);
+    for step in dial.trace(&instructions[..limit]) {
+        password_part1 += step.hits(Count::LandOn);
+        password_part2 += step.hits(Count::PassThrough);
+        if trace.is_some() {
+            traced.push(step);
         }
+    }
 
-        let direction = line.chars().next().unwrap();
-        let distance: i32 = line[1..].trim().parse().expect("Invalid distance");
-
-        // Count how many times we pass through 0 during the rotation
-        zero_count += count_zeros_in_rotation(position, direction, distance);
-
-        // Update position
-        position = match direction {
-            'L' => (position - distance).rem_euclid(100),
-            'R' => (position + distance) % 100,
-            _ => panic!("Invalid direction: {}", direction),
-        };
+    if let Some(format) = trace {
+        print!("{}", render_trace(&traced, format));
+        if format == TraceFormat::Csv {
+            return;
+        }
+        println!();
     }
-    zero_count
+
+    println!("Part 1 Password: {}", password_part1);
+    println!("Part 2 Password: {}", password_part2);
 }
 
-fn count_zeros_in_rotation(start: i32, direction: char, distance: i32) -> i32 {
-    let mut count = 0;
+fn print_sweep(dial: &Dial, instructions: &[Instruction]) {
+    let sweep = dial.sweep(instructions).unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        process::exit(2);
+    });
 
-    match direction {
-        'L' => {
-            // Moving left (decreasing numbers, wrapping at 0)
-            for i in 1..=distance {
-                let pos = (start - i).rem_euclid(100);
-                if pos == 0 {
-                    count += 1;
-                }
-            }
-        }
-        'R' => {
-            // Moving right (increasing numbers, wrapping at 99)
-            for i in 1..=distance {
-                let pos = (start + i) % 100;
-                if pos == 0 {
-                    count += 1;
-                }
-            }
+    println!("Swept {} start positions", dial.size());
+    for (part, mode) in [(1, Count::LandOn), (2, Count::PassThrough)] {
+        let Some(summary) = summarize(sweep.counts(mode)) else {
+            continue;
+        };
+        println!();
+        println!("Part {} Password", part);
+        println!("  Best start:  {} ({})", summary.best.0, summary.best.1);
+        println!("  Worst start: {} ({})", summary.worst.0, summary.worst.1);
+        println!("  Mean: {:.2}", summary.mean);
+        println!("  Distribution:");
+        for (count, starts) in &summary.distribution {
+            println!("    {}: {} start(s)", count, starts);
         }
-        _ => panic!("Invalid direction: {}", direction),
     }
+}
 
-    count
+fn parse_number(option: &str, value: &str) -> Result<u64, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("{} takes whole numbers, got '{}'", option, value))
 }
@@ -0,0 +1,32 @@
+use aoc_core::parse::Mode;
+use aoc_core::solution::{self, Part};
+use aoc_core::Answer;
+use day_01::{render_trace, summarize, Day01, Dial, Instruction, Step, TraceFormat};
+
+#[test]
+fn test_run_lenient() {
+    let outcome = solution::run::<Day01>("R50\r\nbogus\r\nREPEAT 2 { L100 }\r\n", &Part::BOTH, Mode::Lenient).unwrap();
+    assert_eq!(outcome.answers, vec![Answer::Unsigned(3), Answer::Unsigned(3)]);
+    assert_eq!(outcome.skipped.len(), 1);
+    assert_eq!(outcome.skipped[0].line, 2);
+}
+
+#[test]
+fn test_trace_format() {
+    assert_eq!("csv".parse(), Ok(TraceFormat::Csv));
+    assert!("json".parse::<TraceFormat>().is_err());
+
+    let instructions = vec![Instruction::Set { line: 4, position: 7 }];
+    let dial = Dial::default();
+    let steps: Vec<Step> = dial.trace(&instructions).collect();
+    assert_eq!(steps[0].instruction.line(), 4);
+    assert_eq!(render_trace(&steps, TraceFormat::Csv), "line,direction,distance,from,to,crossings\n4,SET,,50,7,0\n");
+}
+
+#[test]
+fn test_summarize() {
+    let summary = summarize(&[2, 5, 1, 5, 1]).unwrap();
+    assert_eq!((summary.best, summary.worst), ((1, 5), (2, 1)));
+    assert_eq!(summary.mean, 2.8);
+    assert_eq!(summary.distribution.into_iter().collect::<Vec<_>>(), vec![(1, 2), (2, 1), (5, 2)]);
+}
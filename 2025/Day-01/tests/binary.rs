@@ -0,0 +1,39 @@
+// Runs the Day-01 binary itself, for the options only main.rs handles
+use std::process::Command;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_Day-01")).args(args).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_size_without_start() {
+    // 50 mod 10 starts the dial on 0
+    assert_eq!(
+        run(&["--size", "10", "--input-str", "R15\nL5"]),
+        "Part 1 Password: 1\nPart 2 Password: 2\n"
+    );
+    assert_eq!(run(&["--size", "60", "--input-str", "L50"]), "Part 1 Password: 1\nPart 2 Password: 1\n");
+}
+
+#[test]
+fn test_sweep_small_dial() {
+    let part = "  Best start:  3 (1)\n  Worst start: 0 (0)\n  Mean: 0.25\n  Distribution:\n    0: 3 start(s)\n    1: 1 start(s)\n";
+    assert_eq!(
+        run(&["--sweep", "--size", "4", "--input-str", "R1"]),
+        format!("Swept 4 start positions\n\nPart 1 Password\n{}\nPart 2 Password\n{}", part, part)
+    );
+}
+
+#[test]
+fn test_until_counts_instructions() {
+    // The whole REPEAT block runs as the first instruction
+    let input = "REPEAT 2 { R25 }\nR50";
+    assert_eq!(run(&["--until", "1", "--input-str", input]), "Part 1 Password: 1\nPart 2 Password: 1\n");
+    assert_eq!(
+        run(&["--until", "1", "--trace", "csv", "--input-str", input]),
+        "line,direction,distance,from,to,crossings\n1,R,25,50,75,0\n1,R,25,75,0,1\n"
+    );
+    assert_eq!(run(&["--until", "5", "--input-str", input]), "Part 1 Password: 1\nPart 2 Password: 1\n");
+}
@@ -0,0 +1,177 @@
+use aoc_core::ranges::parse_ranges;
+use aoc_core::parse::Context;
+use aoc_core::{Answer, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u64, u64)>;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_ranges(Self::DAY, input, ctx)
+    }
+
+    fn part1(ranges: &Self::Input) -> Answer {
+        solve(ranges, false).into()
+    }
+
+    fn part2(ranges: &Self::Input) -> Answer {
+        solve(ranges, true).into()
+    }
+}
+
+// Each listed range is summed on its own, so an ID in two ranges counts twice.
+// IDs near u64::MAX add up past it, so the total is a u128.
+pub fn solve(ranges: &[(u64, u64)], part2: bool) -> u128 {
+    let mut total = 0u128;
+    for &(start, end) in ranges {
+        for id in start..=end {
+            let invalid = if part2 {
+                is_invalid_id_part2(id)
+            } else {
+                is_invalid_id_part1(id)
+            };
+            if invalid {
+                total += id as u128;
+            }
+        }
+    }
+    total
+}
+
+/// Part 1: an ID is invalid if it's a sequence of digits repeated exactly
+/// twice.
+pub fn is_invalid_id_part1(id: u64) -> bool {
+    let s = id.to_string();
+    let len = s.len();
+
+    if !len.is_multiple_of(2) {
+        return false;
+    }
+
+    let mid = len / 2;
+    let first_half = &s[..mid];
+    let second_half = &s[mid..];
+
+    first_half == second_half
+}
+
+/// Part 2: an ID is invalid if it's a sequence of digits repeated at least
+/// twice.
+pub fn is_invalid_id_part2(id: u64) -> bool {
+    let s = id.to_string();
+    let len = s.len();
+
+    // Try all possible pattern lengths from 1 to len/2
+    for pattern_len in 1..=(len / 2) {
+        // Check if the string length is divisible by pattern length
+        if len.is_multiple_of(pattern_len) {
+            let repetitions = len / pattern_len;
+            // We need at least 2 repetitions
+            if repetitions >= 2 {
+                let pattern = &s[..pattern_len];
+                // Check if the entire string is made of this pattern repeated
+                let is_match = s.chars()
+                    .collect::<Vec<_>>()
+                    .chunks(pattern_len)
+                    .all(|chunk| {
+                        let chunk_str: String = chunk.iter().collect();
+                        chunk_str == pattern
+                    });
+
+                if is_match {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    #[test]
+    fn test_is_invalid_id_part1() {
+        // Valid cases for Part 1 (repeated exactly twice)
+        assert!(is_invalid_id_part1(11));
+        assert!(is_invalid_id_part1(22));
+        assert!(is_invalid_id_part1(55));
+        assert!(is_invalid_id_part1(99));
+        assert!(is_invalid_id_part1(6464));
+        assert!(is_invalid_id_part1(123123));
+        assert!(is_invalid_id_part1(1010));
+        assert!(is_invalid_id_part1(1188511885));
+        assert!(is_invalid_id_part1(222222));
+        assert!(is_invalid_id_part1(446446));
+        assert!(is_invalid_id_part1(38593859));
+
+        // Invalid cases for Part 1
+        assert!(!is_invalid_id_part1(101));
+        assert!(!is_invalid_id_part1(1698522));
+        assert!(!is_invalid_id_part1(1698528));
+        assert!(!is_invalid_id_part1(111)); // 3 times, not 2
+        assert!(!is_invalid_id_part1(999)); // 3 times, not 2
+    }
+
+    #[test]
+    fn test_is_invalid_id_part2() {
+        // Part 1 cases still work
+        assert!(is_invalid_id_part2(11));
+        assert!(is_invalid_id_part2(22));
+        assert!(is_invalid_id_part2(6464));
+        assert!(is_invalid_id_part2(123123));
+        assert!(is_invalid_id_part2(1010));
+        assert!(is_invalid_id_part2(222222));
+        assert!(is_invalid_id_part2(446446));
+
+        // New Part 2 cases (repeated 3+ times)
+        assert!(is_invalid_id_part2(111)); // 1 three times
+        assert!(is_invalid_id_part2(999)); // 9 three times
+        assert!(is_invalid_id_part2(12341234)); // 1234 two times
+        assert!(is_invalid_id_part2(123123123)); // 123 three times
+        assert!(is_invalid_id_part2(1212121212)); // 12 five times
+        assert!(is_invalid_id_part2(1111111)); // 1 seven times
+        assert!(is_invalid_id_part2(565656)); // 56 three times
+        assert!(is_invalid_id_part2(824824824)); // 824 three times
+        assert!(is_invalid_id_part2(2121212121)); // 21 five times
+
+        // Still invalid cases
+        assert!(!is_invalid_id_part2(101));
+        assert!(!is_invalid_id_part2(1698522));
+        assert!(!is_invalid_id_part2(1698528));
+    }
+
+    #[test]
+    fn test_part1_example() {
+        let input = fixture!("example");
+
+        let result = solve(&Day02::parse(input).unwrap(), false);
+        assert_eq!(result, 1227775554);
+    }
+
+    #[test]
+    fn test_overlapping_ranges_count_separately() {
+        // 22 is in both ranges, so it's added twice
+        let ranges = Day02::parse("11-22,15-22").unwrap();
+        assert_eq!(ranges, vec![(11, 22), (15, 22)]);
+        assert_eq!(solve(&ranges, false), 11 + 22 + 22);
+
+        let id = 18_446_744_071_844_674_407;
+        assert_eq!(solve(&[(id, id), (id, id)], false), 2 * id as u128);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = fixture!("example");
+
+        let result = solve(&Day02::parse(input).unwrap(), true);
+        assert_eq!(result, 4174379265);
+    }
+}
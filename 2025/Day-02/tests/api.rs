@@ -0,0 +1,18 @@
+use aoc_core::parse::Mode;
+use aoc_core::solution::{self, Part};
+use aoc_core::{Answer, Solution};
+use day_02::Day02;
+
+#[test]
+fn test_run_lenient() {
+    let outcome = solution::run::<Day02>("11-22,x-y\n95-115\n", &Part::BOTH, Mode::Lenient).unwrap();
+    assert_eq!(outcome.answers, vec![Answer::Unsigned(11 + 22 + 99), Answer::Unsigned(11 + 22 + 99 + 111)]);
+    assert_eq!(outcome.skipped.len(), 1);
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = Day02::parse("11-22,5-x").unwrap_err();
+    assert_eq!((err.day, err.line), (2, 1));
+    assert!(Day02::parse("11-22\n\n95-115").is_err());
+}
@@ -0,0 +1,204 @@
+use aoc_core::parse::{lines, Context, Line};
+use aoc_core::{Answer, ParseError, Solution};
+
+// Part 2 turns on exactly this many batteries in each bank
+const PART2_BATTERIES: usize = 12;
+
+// Any number with this many digits fits in a u64
+const _: () = assert!(PART2_BATTERIES <= 19);
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_banks(input, ctx)
+    }
+
+    fn part1(banks: &Self::Input) -> Answer {
+        solve_part1(banks).into()
+    }
+
+    fn part2(banks: &Self::Input) -> Answer {
+        solve_part2(banks).into()
+    }
+}
+
+pub fn parse_banks(input: &str, ctx: &mut Context) -> Result<Vec<String>, ParseError> {
+    let mut banks = Vec::new();
+
+    for line in lines(Day03::DAY, input) {
+        if ctx.skip_blank(&line)? {
+            continue;
+        }
+        banks.extend(ctx.recover(parse_bank(&line))?);
+    }
+
+    Ok(banks)
+}
+
+fn parse_bank(line: &Line) -> Result<String, ParseError> {
+    let bank = line.text.trim();
+
+    if let Some((i, c)) = bank.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(line.error_at(
+            &bank[i..i + c.len_utf8()],
+            format!("invalid battery '{}', expected a digit", c),
+        ));
+    }
+    if bank.len() < PART2_BATTERIES {
+        return Err(line.error_at(
+            bank,
+            format!("bank has {} batteries, at least {} are needed", bank.len(), PART2_BATTERIES),
+        ));
+    }
+
+    Ok(bank.to_string())
+}
+
+pub fn solve_part1(banks: &[String]) -> u32 {
+    banks
+        .iter()
+        .map(String::as_str)
+        .map(find_max_joltage_part1)
+        .sum()
+}
+
+pub fn solve_part2(banks: &[String]) -> u128 {
+    // Each bank is below 10^12, so a u128 total can't overflow
+    banks
+        .iter()
+        .map(String::as_str)
+        .map(|bank| find_max_joltage_part2(bank) as u128)
+        .sum()
+}
+
+/// The largest joltage from turning on two of the bank's batteries.
+pub fn find_max_joltage_part1(bank: &str) -> u32 {
+    let digits: Vec<char> = bank.chars().collect();
+    let mut max_joltage = 0;
+
+    // Try all pairs of batteries (positions i and j where i < j)
+    for i in 0..digits.len() {
+        for j in (i + 1)..digits.len() {
+            // Form a two-digit number from batteries at positions i and j
+            let joltage = digits[i].to_digit(10).unwrap() * 10 + digits[j].to_digit(10).unwrap();
+            max_joltage = max_joltage.max(joltage);
+        }
+    }
+
+    max_joltage
+}
+
+/// The largest joltage from turning on twelve of the bank's batteries.
+pub fn find_max_joltage_part2(bank: &str) -> u64 {
+    let digits: Vec<char> = bank.chars().collect();
+    let n = digits.len();
+
+    // We need to select exactly 12 batteries
+    // To maximize the number, we want the largest digits in the leftmost positions
+    // Strategy: Create all combinations of 12 positions, form the number, keep the max
+
+    // For efficiency, we can use a greedy approach with backtracking
+    // But given the constraints, let's try a smarter greedy approach:
+    // For a 12-digit number to be maximum, we want to pick the 12 largest-valued
+    // positions while maintaining order
+
+    // Actually, we need to pick indices such that the resulting 12-digit number is maximum
+    // This is a combinatorial problem: C(n, 12) combinations
+
+    // Greedy approach: At each position in our result, pick the largest available digit
+    // such that we still have enough remaining digits to complete 12 positions
+
+    let mut selected_indices = Vec::new();
+    let target_count = PART2_BATTERIES;
+
+    for result_pos in 0..target_count {
+        let remaining_needed = target_count - result_pos - 1;
+        let mut best_digit = '0';
+        let mut best_idx = 0;
+
+        // Start searching from the position after the last selected index
+        let start_idx = if selected_indices.is_empty() {
+            0
+        } else {
+            selected_indices.last().unwrap() + 1
+        };
+
+        // We need to leave enough digits after this choice for the remaining positions
+        for (idx, &digit) in digits.iter().enumerate().take(n - remaining_needed).skip(start_idx) {
+            if digit > best_digit {
+                best_digit = digit;
+                best_idx = idx;
+            }
+        }
+
+        selected_indices.push(best_idx);
+    }
+
+    // Convert selected indices to a number
+    let result_str: String = selected_indices.iter()
+        .map(|&idx| digits[idx])
+        .collect();
+
+    result_str.parse::<u64>().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    #[test]
+    fn test_find_max_joltage_part1() {
+        assert_eq!(find_max_joltage_part1("987654321111111"), 98);
+        assert_eq!(find_max_joltage_part1("811111111111119"), 89);
+        assert_eq!(find_max_joltage_part1("234234234234278"), 78);
+        assert_eq!(find_max_joltage_part1("818181911112111"), 92);
+    }
+
+    #[test]
+    fn test_part1_example() {
+        let input = fixture!("example");
+        let result = solve_part1(&Day03::parse(input).unwrap());
+        assert_eq!(result, 357);
+    }
+
+    #[test]
+    fn test_part1_small_cases() {
+        assert_eq!(find_max_joltage_part1("12"), 12);
+        assert_eq!(find_max_joltage_part1("21"), 21);
+        assert_eq!(find_max_joltage_part1("123"), 23);
+        assert_eq!(find_max_joltage_part1("321"), 32);
+        assert_eq!(find_max_joltage_part1("19"), 19);
+        assert_eq!(find_max_joltage_part1("91"), 91);
+    }
+
+    #[test]
+    fn test_find_max_joltage_part2() {
+        assert_eq!(find_max_joltage_part2("987654321111111"), 987654321111);
+        assert_eq!(find_max_joltage_part2("811111111111119"), 811111111119);
+        assert_eq!(find_max_joltage_part2("234234234234278"), 434234234278);
+        assert_eq!(find_max_joltage_part2("818181911112111"), 888911112111);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = fixture!("example");
+        let result = solve_part2(&Day03::parse(input).unwrap());
+        assert_eq!(result, 3121910778619);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day03::parse("987654321111111\n8111111x1111119").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+
+        let err = Day03::parse("98765").unwrap_err();
+        assert_eq!(err.message, "bank has 5 batteries, at least 12 are needed");
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_core::parse::Mode;
+use aoc_core::solution::{self, Part};
+use aoc_core::Answer;
+use day_03::Day03;
+
+#[test]
+fn test_run_lenient() {
+    let input = "987654321111111\n12x\n811111111111119\n";
+    let outcome = solution::run::<Day03>(input, &Part::BOTH, Mode::Lenient).unwrap();
+    assert_eq!(outcome.answers, vec![Answer::Unsigned(98 + 89), Answer::Unsigned(987654321111 + 811111111119)]);
+    assert_eq!(outcome.skipped.len(), 1);
+    assert_eq!(outcome.skipped[0].line, 2);
+}
+
+#[test]
+fn test_run_strict() {
+    let err = solution::run::<Day03>("1234", &Part::BOTH, Mode::Strict).unwrap_err();
+    assert_eq!(err.message, "bank has 4 batteries, at least 12 are needed");
+}
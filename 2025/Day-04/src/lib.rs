@@ -0,0 +1,201 @@
+use aoc_core::grid::{parse_char_grid, Grid};
+use aoc_core::parse::Context;
+use aoc_core::{Answer, ParseError, Solution};
+use std::fmt;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_grid(input, ctx)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        count_accessible_rolls(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        remove_all_accessible_rolls(grid.clone()).into()
+    }
+}
+
+pub fn parse_grid(input: &str, ctx: &mut Context) -> Result<Grid<char>, ParseError> {
+    parse_char_grid(Day04::DAY, input, &['@', '.'], ctx)
+}
+
+/// Parses a grid annotated the way the README draws it, with each
+/// accessible roll marked `x` instead of `@`.
+pub fn parse_annotated(input: &str, ctx: &mut Context) -> Result<Grid<char>, ParseError> {
+    parse_char_grid(Day04::DAY, input, &['@', '.', 'x'], ctx)
+}
+
+pub fn count_accessible_rolls(grid: &Grid<char>) -> usize {
+    // A roll is accessible if there are fewer than 4 adjacent rolls
+    grid.find_all(&'@')
+        .filter(|&pos| count_adjacent_rolls(grid, pos) < 4)
+        .count()
+}
+
+/// The number of rolls in the eight cells around `pos`.
+pub fn count_adjacent_rolls(grid: &Grid<char>, pos: (usize, usize)) -> usize {
+    grid.neighbors_8(pos).filter(|&pos| grid[pos] == '@').count()
+}
+
+pub fn remove_all_accessible_rolls(mut grid: Grid<char>) -> usize {
+    let mut total_removed = 0;
+
+    loop {
+        // Find all accessible rolls in current state
+        let to_remove: Vec<(usize, usize)> = grid
+            .find_all(&'@')
+            .filter(|&pos| count_adjacent_rolls(&grid, pos) < 4)
+            .collect();
+
+        // If no more accessible rolls, we're done
+        if to_remove.is_empty() {
+            break;
+        }
+
+        // Remove all accessible rolls
+        for &pos in &to_remove {
+            grid[pos] = '.';
+        }
+
+        total_removed += to_remove.len();
+    }
+
+    total_removed
+}
+
+/// A cell where the solver's view disagrees with an annotated grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub pos: (usize, usize),
+    /// The cell in the input grid.
+    pub cell: char,
+    pub adjacent_rolls: usize,
+    /// How the annotated grid marks the cell.
+    pub marked: char,
+}
+
+impl Mismatch {
+    /// How the solver would mark the cell.
+    pub fn expected(&self) -> char {
+        annotate(self.cell, self.adjacent_rolls)
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: '{}' with {} adjacent rolls should be '{}', but is marked '{}'",
+            self.pos.0 + 1,
+            self.pos.1 + 1,
+            self.cell,
+            self.adjacent_rolls,
+            self.expected(),
+            self.marked
+        )
+    }
+}
+
+/// The result of checking the solver against an annotated grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Accessible rolls according to `count_accessible_rolls`.
+    pub counted: usize,
+    /// Rolls the annotated grid marks accessible.
+    pub marked: usize,
+    pub mismatches: Vec<Mismatch>,
+}
+
+fn annotate(cell: char, adjacent_rolls: usize) -> char {
+    if cell == '@' && adjacent_rolls < 4 { 'x' } else { cell }
+}
+
+/// Compares the solver's view of `grid` with `annotated` cell by cell.
+pub fn diagnose(grid: &Grid<char>, annotated: &Grid<char>) -> Result<Diagnosis, String> {
+    if (grid.rows(), grid.cols()) != (annotated.rows(), annotated.cols()) {
+        return Err(format!(
+            "the annotated grid is {}x{}, but the input is {}x{}",
+            annotated.rows(),
+            annotated.cols(),
+            grid.rows(),
+            grid.cols()
+        ));
+    }
+
+    let mismatches = grid
+        .iter()
+        .map(|(pos, &cell)| Mismatch {
+            pos,
+            cell,
+            adjacent_rolls: count_adjacent_rolls(grid, pos),
+            marked: annotated[pos],
+        })
+        .filter(|mismatch| mismatch.expected() != mismatch.marked)
+        .collect();
+
+    Ok(Diagnosis {
+        counted: count_accessible_rolls(grid),
+        marked: annotated.find_all(&'x').count(),
+        mismatches,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    #[test]
+    fn test_part1_example() {
+        let input = Day04::parse(fixture!("example")).unwrap();
+
+        assert_eq!(count_accessible_rolls(&input), 13);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = Day04::parse(fixture!("example")).unwrap();
+
+        assert_eq!(remove_all_accessible_rolls(input), 43);
+    }
+
+    #[test]
+    fn test_diagnose_example() {
+        let grid = Day04::parse(fixture!("example")).unwrap();
+        let annotated = parse_annotated(fixture!("example-2"), &mut Context::strict()).unwrap();
+
+        let diagnosis = diagnose(&grid, &annotated).unwrap();
+        assert_eq!((diagnosis.counted, diagnosis.marked), (13, 13));
+        assert!(diagnosis.mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_diagnose_mismatches() {
+        let grid = Day04::parse("@@@\n@@@").unwrap();
+        let annotated = parse_annotated("x@x\n@x.", &mut Context::strict()).unwrap();
+
+        let diagnosis = diagnose(&grid, &annotated).unwrap();
+        assert_eq!((diagnosis.counted, diagnosis.marked), (4, 3));
+        let report: Vec<String> = diagnosis.mismatches.iter().map(Mismatch::to_string).collect();
+        assert_eq!(
+            report,
+            vec![
+                "line 2, column 1: '@' with 3 adjacent rolls should be 'x', but is marked '@'",
+                "line 2, column 2: '@' with 5 adjacent rolls should be '@', but is marked 'x'",
+                "line 2, column 3: '@' with 3 adjacent rolls should be 'x', but is marked '.'",
+            ]
+        );
+
+        let small = parse_annotated("x@", &mut Context::strict()).unwrap();
+        assert!(diagnose(&grid, &small).is_err());
+    }
+}
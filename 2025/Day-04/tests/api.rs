@@ -0,0 +1,27 @@
+use aoc_core::parse::Context;
+use aoc_core::Solution;
+use day_04::{count_adjacent_rolls, parse_annotated, Day04, Mismatch};
+
+#[test]
+fn test_count_adjacent_rolls() {
+    let grid = Day04::parse("@@@\n@.@\n@@@").unwrap();
+    assert_eq!(count_adjacent_rolls(&grid, (1, 1)), 8);
+    assert_eq!(count_adjacent_rolls(&grid, (0, 0)), 2);
+    assert_eq!(count_adjacent_rolls(&grid, (0, 1)), 4);
+}
+
+#[test]
+fn test_parse_annotated() {
+    assert!(parse_annotated("x@.", &mut Context::strict()).is_ok());
+    let err = parse_annotated("x@#", &mut Context::strict()).unwrap_err();
+    assert_eq!((err.day, err.line, err.column), (4, 1, 3));
+    assert!(Day04::parse("x@.").is_err());
+}
+
+#[test]
+fn test_mismatch_expected() {
+    let mismatch = Mismatch { pos: (0, 0), cell: '@', adjacent_rolls: 3, marked: '@' };
+    assert_eq!(mismatch.expected(), 'x');
+    let mismatch = Mismatch { pos: (0, 0), cell: '.', adjacent_rolls: 0, marked: 'x' };
+    assert_eq!(mismatch.expected(), '.');
+}
@@ -0,0 +1,32 @@
+// Runs the Day-04 binary itself, for the diagnose subcommand main.rs handles
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    let args = args.iter().map(|arg| arg.strip_prefix("fixture:").map_or((*arg).into(), |name| fixtures.join(name)));
+    Command::new(env!("CARGO_BIN_EXE_Day-04")).args(args).output().unwrap()
+}
+
+#[test]
+fn test_diagnose() {
+    let output = run(&["diagnose", "fixture:example.txt", "--annotated", "fixture:example-2.txt"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Accessible rolls: 13 counted, 13 marked\nNo mismatches\n");
+
+    // The unmarked grid disagrees wherever a roll is accessible
+    let output = run(&["diagnose", "fixture:example.txt", "--annotated", "fixture:example.txt"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8(output.stdout).unwrap().matches("MISMATCH").count(), 13);
+}
+
+#[test]
+fn test_diagnose_usage_errors() {
+    for args in [
+        &["diagnose", "fixture:example.txt"][..],
+        &["fixture:example.txt", "--annotated", "fixture:example-2.txt"],
+        &["diagnose", "fixture:example.txt", "--annotated", "fixture:example-2.txt", "--format", "json"],
+    ] {
+        assert_eq!(run(args).status.code(), Some(2), "{:?}", args);
+    }
+}
@@ -0,0 +1,150 @@
+use aoc_core::input::sections;
+use aoc_core::parse::{end_of_input, Context};
+use aoc_core::ranges::{parse_range, IntervalSet};
+use aoc_core::{Answer, ParseError, Solution};
+
+pub struct Day05;
+
+#[derive(Debug, Default)]
+pub struct Inventory {
+    pub ranges: IntervalSet<u64>,
+    pub ingredients: Vec<u64>,
+}
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input = Inventory;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_inventory(input, ctx)
+    }
+
+    fn part1(inventory: &Self::Input) -> Answer {
+        count_fresh_ingredients(inventory).into()
+    }
+
+    fn part2(inventory: &Self::Input) -> Answer {
+        count_total_fresh_ids(&inventory.ranges).into()
+    }
+}
+
+// The database lists fresh ID ranges, a blank line, then the available
+// ingredient IDs
+pub fn parse_inventory(input: &str, ctx: &mut Context) -> Result<Inventory, ParseError> {
+    let mut inventory = Inventory::default();
+    let mut sections = sections(Day05::DAY, input).into_iter();
+
+    let (Some(ranges), Some(ingredients)) = (sections.next(), sections.next()) else {
+        return Err(end_of_input(
+            Day05::DAY,
+            input,
+            "expected a blank line followed by the ingredient IDs",
+        ));
+    };
+
+    for line in &ranges.blanks_before {
+        ctx.skip_blank(line)?;
+    }
+    for line in &ranges.lines {
+        inventory.ranges.extend(ctx.recover(parse_range(line, line.text))?);
+    }
+
+    // Only one blank line belongs between the two lists; any more are
+    // stray, like blank lines among the IDs
+    let stray_blanks = ingredients.blanks_before.iter().skip(1);
+    let rest = sections.flat_map(|section| section.blanks_before.into_iter().chain(section.lines));
+    for line in stray_blanks.chain(&ingredients.lines).copied().chain(rest) {
+        if !ctx.skip_blank(&line)? {
+            inventory.ingredients.extend(ctx.recover(line.parse_at::<u64>(line.text.trim(), "ingredient ID"))?);
+        }
+    }
+
+    Ok(inventory)
+}
+
+pub fn count_fresh_ingredients(inventory: &Inventory) -> usize {
+    inventory
+        .ingredients
+        .iter()
+        .filter(|&&id| inventory.ranges.contains(id))
+        .count()
+}
+
+pub fn count_total_fresh_ids(ranges: &IntervalSet<u64>) -> u128 {
+    // The set keeps its ranges merged, so its length counts each ID once.
+    // Every u64 being fresh is one more than a u64 can count.
+    ranges.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    const EXAMPLE: &str = fixture!("example");
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(count_fresh_ingredients(&Day05::parse(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(count_total_fresh_ids(&Day05::parse(EXAMPLE).unwrap().ranges), 14);
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        let inventory = Day05::parse(EXAMPLE).unwrap();
+        let fresh: Vec<bool> = inventory.ingredients.iter().map(|&id| inventory.ranges.contains(id)).collect();
+        assert_eq!(fresh, vec![false, true, false, true, true, false]);
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        // 10-14, 16-20 and 12-18 merge into 10-20, and 3-5 stays separate
+        let inventory = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(inventory.ranges.ranges(), &[(3, 5), (10, 20)]);
+    }
+
+    #[test]
+    fn test_part2_full_range() {
+        let inventory = Day05::parse("0-18446744073709551615\n\n1").unwrap();
+        assert_eq!(count_total_fresh_ids(&inventory.ranges), 1 << 64);
+        assert_eq!(Day05::part2(&inventory).kind(), "u128");
+    }
+
+    #[test]
+    fn test_parse_crlf() {
+        let inventory = Day05::parse(&EXAMPLE.replace('\n', "\r\n")).unwrap();
+        assert_eq!(count_fresh_ingredients(&inventory), 3);
+        assert_eq!(inventory.ingredients.len(), 6);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day05::parse("3-5\n10-14\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+
+        let err = Day05::parse("3-5\n10_14\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (2, 1, 5));
+
+        let err = Day05::parse("3-5\n\n1\nfive").unwrap_err();
+        assert_eq!(err.message, "invalid ingredient ID 'five'");
+
+        assert!(Day05::parse("3-5\n\n1\n\n5").is_err());
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let mut ctx = Context::lenient();
+        let inventory = parse_inventory("3-5\nx\n\n1\n\nfive\n5", &mut ctx).unwrap();
+        assert_eq!(inventory.ranges.ranges(), &[(3, 5)]);
+        assert_eq!(inventory.ingredients, vec![1, 5]);
+
+        let skipped: Vec<_> = ctx.skipped().iter().map(|err| err.line).collect();
+        assert_eq!(skipped, vec![2, 5, 6]);
+    }
+}
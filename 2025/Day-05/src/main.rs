@@ -1,56 +1,18 @@
-use std::fs;
+use aoc_core::cli::{self, Format, InputArgs};
+use aoc_core::Solution;
+use day_05::Day05;
 
 fn main() {
-    let inupt = fs::read_to_string("input.txt")
-        .expect("Failed to read input.txt");
-    let fresh_cuont = count_fresh_ingedient(&input);
-
-}
-
-fn count_fresh_ingedient(input: &str) -> usize {
-    let parts: Vec<&str> = input.split("\n\n").collect();
-
-    if parts.len() != 2 {
-        eprintln!("Invalid input format");
+    let args = InputArgs::from_env();
+    if args.format == Format::Json {
+        return cli::print_json::<Day05>(&args);
     }
 
-    // Parse ranges
-    let ranges: Vec<(u64, u64)> = parts[0]
-        .lines()
-        .filter_map(|line| {
-            let nums: Vec<&str> = line.split('-').collect();
-            if nums.len() == 2 {
-                let start = nums[0].parse::<u64>().ok()?;
-                let end = nums[1].parse::<u64>().ok()?;
-                Some((start, end));
-            } else {
-                None
-            }
-        }).collect();
-
-        // Parse Ingredient IDs
-        let ingredient_ids: Vec<u64> = parts[1]
-            .lines()
-            .filter_map(|line| line.trim().parse::<u64>().ok())
-            .collect();
+    let inventory = cli::load::<Day05>(&args);
 
-        // Count fresh ingredients
-        let mut fresh_count = 0;
-        for &id in &ingredient_ids {
-            if is_fresh(id, &ranges) {
-                fresh_count += 1;
-            }
-        }
+    let fresh_count = Day05::part1(&inventory);
+    println!("Part 1 - Number of fresh ingredients: {}", fresh_count);
 
-        fresh_count
-
-    }
-
-fn is_fresh(id: u64, ranges: &[&(u64, u64)]) -> bool {
-    for &(start, end) in ranges {
-        if id >= start &&  id <= end {
-            return true;
-        }
-    }
-    false
-}
\ No newline at end of file
+    let total_fresh_ids = Day05::part2(&inventory);
+    println!("Part 2 - Total fresh IDs in ranges: {}", total_fresh_ids);
+}
@@ -0,0 +1,15 @@
+use aoc_core::ranges::IntervalSet;
+use day_05::{count_fresh_ingredients, count_total_fresh_ids, Inventory};
+
+#[test]
+fn test_hand_built_inventory() {
+    let inventory = Inventory {
+        ranges: IntervalSet::from_iter([(10, 20), (0, 2), (15, 30), (31, 31)]),
+        ingredients: vec![0, 3, 21, 31, 32, u64::MAX],
+    };
+    assert_eq!(inventory.ranges.ranges(), &[(0, 2), (10, 31)]);
+    assert_eq!(count_fresh_ingredients(&inventory), 3);
+    assert_eq!(count_total_fresh_ids(&inventory.ranges), 3 + 22);
+
+    assert_eq!(count_total_fresh_ids(&IntervalSet::new()), 0);
+}
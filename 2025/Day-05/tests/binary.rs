@@ -0,0 +1,24 @@
+// Builds and runs the Day-05 binary itself, so a main.rs that stops
+// compiling or stops printing both parts fails the test suite
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_binary_solves_example() {
+    let example = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/example.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_Day-05")).arg(&example).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout,
+        "Part 1 - Number of fresh ingredients: 3\nPart 2 - Total fresh IDs in ranges: 14\n"
+    );
+}
+
+#[test]
+fn test_binary_rejects_bad_input() {
+    let output = Command::new(env!("CARGO_BIN_EXE_Day-05")).args(["--input-str", "3-5\n\nfive"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid ingredient ID 'five'"));
+}
@@ -0,0 +1,351 @@
+use aoc_core::bigint::BigUint;
+use aoc_core::grid::Grid;
+use aoc_core::input::{column_blocks, padded_grid};
+use aoc_core::parse::{end_of_input, lines, Context, Line};
+use aoc_core::{Answer, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_worksheet(input, ctx)
+    }
+
+    fn part1(worksheet: &Self::Input) -> Answer {
+        grand_total(&worksheet.part1).into()
+    }
+
+    fn part2(worksheet: &Self::Input) -> Answer {
+        grand_total(&worksheet.part2).into()
+    }
+}
+
+// The same worksheet holds different problems depending on how it is read:
+// row by row for part 1, and column by column (right-to-left) for part 2
+#[derive(Debug)]
+pub struct Worksheet {
+    pub part1: Vec<Problem>,
+    pub part2: Vec<Problem>,
+}
+
+pub fn parse_worksheet(input: &str, ctx: &mut Context) -> Result<Worksheet, ParseError> {
+    let rows = worksheet_rows(input, ctx)?;
+
+    // Pad the rows out to a grid so a trailing space lost to an editor can't
+    // shift or drop a column, then split it into one block per problem
+    let texts: Vec<&str> = rows.iter().map(|row| row.text).collect();
+    let grid = padded_grid(&texts);
+    let mut worksheet = Worksheet { part1: Vec::new(), part2: Vec::new() };
+
+    for block in column_blocks(&grid) {
+        let operator = block_operator(&grid, &rows, &block)?;
+        worksheet.part1.extend(read_rows(&grid, &rows, &block, operator, ctx)?);
+        worksheet.part2.extend(read_columns(&grid, &rows, &block, operator)?);
+    }
+
+    Ok(worksheet)
+}
+
+// The one operator under a block. A hand-edited worksheet can lose or gain
+// one, which would otherwise quietly drop or misread a problem.
+fn block_operator(grid: &Grid<char>, rows: &[Line], block: &RangeInclusive<usize>) -> Result<char, ParseError> {
+    let line = rows.last().expect("the operator row is always present");
+    let mut operators = block.clone().filter(|&col| grid[(grid.rows() - 1, col)] != ' ');
+    let columns = format!("columns {}-{}", block.start() + 1, block.end() + 1);
+
+    let Some(first) = operators.next() else {
+        return Err(line.error(
+            block.start() + 1,
+            block.end() - block.start() + 1,
+            format!("missing an operator under the problem in {}", columns),
+        ));
+    };
+    if let Some(extra) = operators.next() {
+        return Err(line.error(extra + 1, 1, format!("the problem in {} has more than one operator", columns)));
+    }
+    Ok(grid[(grid.rows() - 1, first)])
+}
+
+// Rows of digits and spaces, followed by a row of operators. Lenient mode
+// can drop bad rows of numbers, but never the operator row.
+fn worksheet_rows<'a>(input: &'a str, ctx: &mut Context) -> Result<Vec<Line<'a>>, ParseError> {
+    let lines: Vec<Line> = lines(Day06::DAY, input).collect();
+    let Some((operators, numbers)) = lines.split_last() else {
+        return Err(end_of_input(Day06::DAY, input, "worksheet is empty"));
+    };
+
+    let mut rows = Vec::new();
+    for line in numbers {
+        if ctx.skip_blank(line)? {
+            continue;
+        }
+        rows.extend(ctx.recover(parse_number_row(line))?);
+    }
+
+    if rows.is_empty() {
+        return Err(operators.error(1, operators.text.len(), "expected rows of numbers above the operators"));
+    }
+
+    if let Some((i, c)) = operators.text.char_indices().find(|&(_, c)| !matches!(c, '+' | '*' | ' ')) {
+        return Err(operators.error_at(
+            &operators.text[i..i + c.len_utf8()],
+            format!("unexpected '{}' in the operator row, expected '+' or '*'", c),
+        ));
+    }
+    rows.push(*operators);
+
+    Ok(rows)
+}
+
+fn parse_number_row<'a>(line: &Line<'a>) -> Result<Line<'a>, ParseError> {
+    if let Some((i, c)) = line.text.char_indices().find(|&(_, c)| !c.is_ascii_digit() && c != ' ') {
+        return Err(line.error_at(
+            &line.text[i..i + c.len_utf8()],
+            format!("unexpected '{}' in a row of numbers", c),
+        ));
+    }
+    for number in line.text.split_whitespace() {
+        line.parse_at::<u64>(number, "number")?;
+    }
+    Ok(*line)
+}
+
+// Part 1 reads each row of a block as one number, so a space inside one is
+// an error rather than two numbers or none. Lenient mode drops that number.
+fn read_rows(
+    grid: &Grid<char>,
+    rows: &[Line],
+    block: &RangeInclusive<usize>,
+    operator: char,
+    ctx: &mut Context,
+) -> Result<Option<Problem>, ParseError> {
+    let mut numbers = Vec::new();
+
+    for (row, line) in rows[..rows.len() - 1].iter().enumerate() {
+        let text: String = grid.row(row)[block.clone()].iter().collect();
+        let digits = text.trim();
+        if digits.is_empty() {
+            continue;
+        }
+        let number = if digits.contains(' ') {
+            let start = block.start() + text.len() - text.trim_start().len();
+            Err(line.error(
+                start + 1,
+                digits.len(),
+                format!("stray space inside the number in columns {}-{}", start + 1, start + digits.len()),
+            ))
+        } else {
+            // Every whole number was checked to fit when the row was parsed
+            Ok(digits.parse::<u64>().expect("number fits in a u64"))
+        };
+        numbers.extend(ctx.recover(number)?);
+    }
+
+    Ok((!numbers.is_empty()).then_some(Problem { numbers, operator }))
+}
+
+// Part 2 reads each column of a block top to bottom as one number, taking the
+// columns right to left
+fn read_columns(
+    grid: &Grid<char>,
+    rows: &[Line],
+    block: &RangeInclusive<usize>,
+    operator: char,
+) -> Result<Option<Problem>, ParseError> {
+    let mut numbers = Vec::new();
+
+    for col in block.clone().rev() {
+        let digits: String = grid.column(col).take(grid.rows() - 1).filter(|c| c.is_ascii_digit()).collect();
+        if digits.is_empty() {
+            continue;
+        }
+        let number = digits.parse().map_err(|_| {
+            let last = rows.len() - 2;
+            rows[last].error(col + 1, 1, format!("column {} has too many digits for a u64", col + 1))
+        })?;
+        numbers.push(number);
+    }
+
+    Ok((!numbers.is_empty()).then_some(Problem { numbers, operator }))
+}
+
+pub fn grand_total(problems: &[Problem]) -> BigUint {
+    problems.iter()
+        .map(evaluate_problem)
+        .fold(BigUint::zero(), |total, result| total + &result)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub numbers: Vec<u64>,
+    /// `+` or `*`.
+    pub operator: char,
+}
+
+// A product of a handful of large numbers quickly outgrows a u64, so work in
+// u64 while the checked arithmetic allows and promote to a BigUint after
+pub fn evaluate_problem(problem: &Problem) -> BigUint {
+    let Some((&first, rest)) = problem.numbers.split_first() else {
+        return BigUint::zero();
+    };
+
+    let mut result = first;
+    for (i, &num) in rest.iter().enumerate() {
+        let next = match problem.operator {
+            '+' => result.checked_add(num),
+            '*' => result.checked_mul(num),
+            _ => Some(result),
+        };
+        match next {
+            Some(next) => result = next,
+            None => return evaluate_big(BigUint::from(result), problem.operator, &rest[i..]),
+        }
+    }
+
+    BigUint::from(result)
+}
+
+fn evaluate_big(mut result: BigUint, operator: char, numbers: &[u64]) -> BigUint {
+    for &num in numbers {
+        match operator {
+            '+' => result += &BigUint::from(num),
+            '*' => result *= &BigUint::from(num),
+            _ => {}
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    #[test]
+    fn test_part1_example() {
+        let input = fixture!("example");
+
+        let result = grand_total(&Day06::parse(input).unwrap().part1);
+        assert_eq!(result, BigUint::from(4277556u64));
+    }
+
+    #[test]
+    fn test_part1_individual_problems() {
+        let input = fixture!("example");
+
+        let problems = Day06::parse(input).unwrap().part1;
+
+        assert_eq!(problems.len(), 4);
+
+        // Problem 1: 123 * 45 * 6 = 33210
+        assert_eq!(evaluate_problem(&problems[0]).to_u64(), Some(33210));
+
+        // Problem 2: 328 + 64 + 98 = 490
+        assert_eq!(evaluate_problem(&problems[1]).to_u64(), Some(490));
+
+        // Problem 3: 51 * 387 * 215 = 4243455
+        assert_eq!(evaluate_problem(&problems[2]).to_u64(), Some(4243455));
+
+        // Problem 4: 64 + 23 + 314 = 401
+        assert_eq!(evaluate_problem(&problems[3]).to_u64(), Some(401));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = fixture!("example");
+
+        let result = grand_total(&Day06::parse(input).unwrap().part2);
+        assert_eq!(result, BigUint::from(3263827u64));
+    }
+
+    #[test]
+    fn test_part2_individual_problems() {
+        let input = fixture!("example");
+
+        let problems = Day06::parse(input).unwrap().part2;
+
+        assert_eq!(problems.len(), 4);
+
+        // Problem parsing is left-to-right, but reading is right-to-left per problem
+        // Leftmost problem (index 0): 356 * 24 * 1 = 8544
+        assert_eq!(evaluate_problem(&problems[0]).to_u64(), Some(8544));
+
+        // Second problem (index 1): 8 + 248 + 369 = 625
+        assert_eq!(evaluate_problem(&problems[1]).to_u64(), Some(625));
+
+        // Third problem (index 2): 175 * 581 * 32 = 3253600
+        assert_eq!(evaluate_problem(&problems[2]).to_u64(), Some(3253600));
+
+        // Rightmost problem (index 3): 4 + 431 + 623 = 1058
+        assert_eq!(evaluate_problem(&problems[3]).to_u64(), Some(1058));
+    }
+
+    #[test]
+    fn test_overflow_promotes() {
+        let max = u64::MAX as u128;
+        let product = Problem { numbers: vec![u64::MAX, u64::MAX, 3], operator: '*' };
+        assert_eq!(evaluate_problem(&product).to_string(), "1020847100762815390279443357853047324675");
+
+        let sum = Problem { numbers: vec![u64::MAX, u64::MAX], operator: '+' };
+        assert_eq!(evaluate_problem(&sum).to_u128(), Some(2 * max));
+
+        let worksheet = Day06::parse("18446744073709551615\n18446744073709551615\n*").unwrap();
+        assert_eq!(Day06::part1(&worksheet), Answer::Wide(max * max));
+    }
+
+    #[test]
+    fn test_stripped_trailing_spaces() {
+        let stripped: Vec<&str> = fixture!("example").lines().map(str::trim_end).collect();
+        let worksheet = Day06::parse(&stripped.join("\r\n")).unwrap();
+        assert_eq!(grand_total(&worksheet.part1), BigUint::from(4277556u64));
+        assert_eq!(grand_total(&worksheet.part2), BigUint::from(3263827u64));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day06::parse("123 328\n 45 6x\n*   +").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+
+        let err = Day06::parse("123 328\n 45 64\n*   -").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+
+        assert!(Day06::parse("").is_err());
+        assert!(Day06::parse("*   +").is_err());
+        // Every problem needs exactly one operator
+        let err = Day06::parse("12 3\n45 6\n   *").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (3, 1, 2));
+        assert_eq!(err.message, "missing an operator under the problem in columns 1-2");
+
+        let err = Day06::parse("12 3\n45 6\n*+ *").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (3, 2, 1));
+        assert_eq!(err.message, "the problem in columns 1-2 has more than one operator");
+
+        let err = Day06::parse("1 2 4
+345 6
+*   +").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (1, 1, 3));
+        assert_eq!(err.message, "stray space inside the number in columns 1-3");
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let mut ctx = Context::lenient();
+        let worksheet = parse_worksheet("123 328\n 45 6x\n\n  6 98\n*   +  ", &mut ctx).unwrap();
+        assert_eq!(grand_total(&worksheet.part1), BigUint::from(123 * 6 + 328 + 98u64));
+
+        let skipped: Vec<_> = ctx.skipped().iter().map(|err| err.line).collect();
+        assert_eq!(skipped, vec![2, 3]);
+
+        // Only the number with the space in it is dropped
+        let mut ctx = Context::lenient();
+        let worksheet = parse_worksheet("1 2 4\n345 6\n*   +", &mut ctx).unwrap();
+        assert_eq!(grand_total(&worksheet.part1), BigUint::from(345 + 4 + 6u64));
+        assert_eq!(ctx.skipped().iter().map(|err| err.line).collect::<Vec<_>>(), vec![1]);
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_core::bigint::BigUint;
+use aoc_core::parse::Mode;
+use aoc_core::solution::{self, Part};
+use aoc_core::Answer;
+use day_06::{evaluate_problem, grand_total, Day06, Problem};
+
+#[test]
+fn test_hand_built_problems() {
+    let problems = vec![
+        Problem { numbers: vec![1, 2, 3], operator: '+' },
+        Problem { numbers: vec![4, 5], operator: '*' },
+        Problem { numbers: vec![], operator: '*' },
+    ];
+    assert_eq!(evaluate_problem(&problems[2]), BigUint::zero());
+    assert_eq!(grand_total(&problems), BigUint::from(26u64));
+    assert_eq!(grand_total(&[]), BigUint::zero());
+}
+
+#[test]
+fn test_run_answer_types() {
+    // Part 1 multiplies two 20-digit numbers, which only a big integer holds
+    let input = "18446744073709551615 1\n18446744073709551615 2\n*                    +";
+    let outcome = solution::run::<Day06>(input, &Part::BOTH, Mode::Strict).unwrap();
+    assert_eq!(outcome.answers[0].kind(), "u128");
+    assert_eq!(outcome.answers[0], Answer::from(u64::MAX as u128 * u64::MAX as u128 + 3));
+}
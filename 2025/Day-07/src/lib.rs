@@ -0,0 +1,222 @@
+use aoc_core::bigint::BigUint;
+use aoc_core::grid::{parse_char_grid, Grid};
+use aoc_core::parse::{lines, Context};
+use aoc_core::{Answer, ParseError, Solution};
+use std::collections::{HashSet, VecDeque};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input = Grid<char>;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_grid(input, ctx)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        simulate_beams(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        count_timelines(grid).into()
+    }
+}
+
+pub fn parse_grid(input: &str, ctx: &mut Context) -> Result<Grid<char>, ParseError> {
+    let grid = parse_char_grid(Day07::DAY, input, &['.', '^', 'S'], ctx)?;
+
+    // The beam enters from the top row
+    if !grid.row(0).contains(&'S') {
+        let first = lines(Day07::DAY, input)
+            .find(|line| line.text.chars().eq(grid.row(0).iter().copied()))
+            .unwrap();
+        return Err(first.error_at(first.text, "expected the start 'S' in the first row"));
+    }
+
+    Ok(grid)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Beam {
+    row: usize,
+    col: usize,
+}
+
+pub fn simulate_beams(grid: &Grid<char>) -> usize {
+    let (rows, cols) = (grid.rows(), grid.cols());
+
+    // Find the starting position (S)
+    let (_, start_col) = grid.find(&'S').unwrap_or((0, 0));
+
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    let mut splitters_hit = HashSet::new(); // Track which splitters have been hit
+
+    // Start with a beam at position S
+    queue.push_back(Beam { row: 0, col: start_col });
+
+    while let Some(beam) = queue.pop_front() {
+        // Check if we've already processed this beam position
+        if visited.contains(&beam) {
+            continue;
+        }
+        visited.insert(beam);
+
+        // Move beam downward
+        let mut current_row = beam.row;
+        let current_col = beam.col;
+
+        // Keep moving down until we hit a splitter or exit the grid
+        loop {
+            current_row += 1;
+
+            // Check if beam exits the grid
+            if current_row >= rows {
+                break;
+            }
+
+            let cell = grid[(current_row, current_col)];
+
+            if cell == '^' {
+                // Hit a splitter - record it
+                let splitter_pos = (current_row, current_col);
+                splitters_hit.insert(splitter_pos);
+
+                // Create left beam
+                if current_col > 0 {
+                    let left_beam = Beam {
+                        row: current_row,
+                        col: current_col - 1,
+                    };
+                    if !visited.contains(&left_beam) {
+                        queue.push_back(left_beam);
+                    }
+                }
+
+                // Create right beam
+                if current_col + 1 < cols {
+                    let right_beam = Beam {
+                        row: current_row,
+                        col: current_col + 1,
+                    };
+                    if !visited.contains(&right_beam) {
+                        queue.push_back(right_beam);
+                    }
+                }
+
+                break; // Stop this beam
+            }
+            // Otherwise continue downward (empty space '.')
+        }
+    }
+
+    splitters_hit.len()
+}
+
+pub fn count_timelines(grid: &Grid<char>) -> BigUint {
+    let (rows, cols) = (grid.rows(), grid.cols());
+
+    // Find the starting position (S)
+    let (_, start_col) = grid.find(&'S').unwrap_or((0, 0));
+
+    // Use dynamic programming approach
+    // For each position, track how many unique paths lead to it
+    // Key: (row, col), Value: number of timelines reaching this point.
+    // Every splitter can double the count, so it soon outgrows any fixed width.
+    let mut paths_at: std::collections::HashMap<(usize, usize), BigUint> = std::collections::HashMap::new();
+    paths_at.insert((0, start_col), BigUint::from(1u64));
+
+    let mut total_timelines = BigUint::zero();
+
+    // Process row by row
+    for row in 0..rows {
+        // Get all positions in current row that have paths
+        let current_positions: Vec<(usize, usize)> = paths_at
+            .keys()
+            .filter(|(r, _)| *r == row)
+            .copied()
+            .collect();
+
+        for (_, col) in current_positions {
+            // Remove from map as we process
+            let count = paths_at.remove(&(row, col)).unwrap();
+
+            // Move downward until hitting splitter or exit
+            let mut current_row = row;
+            loop {
+                current_row += 1;
+
+                // Check if beam exits the grid
+                if current_row >= rows {
+                    total_timelines += &count;
+                    break;
+                }
+
+                let cell = grid[(current_row, col)];
+
+                if cell == '^' {
+                    // Hit a splitter - split paths
+                    // Left path
+                    if col > 0 {
+                        *paths_at.entry((current_row, col - 1)).or_default() += &count;
+                    }
+                    // Right path
+                    if col + 1 < cols {
+                        *paths_at.entry((current_row, col + 1)).or_default() += &count;
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    total_timelines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    #[test]
+    fn test_example_part1() {
+        let input = fixture!("example");
+
+        let result = simulate_beams(&Day07::parse(input).unwrap());
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = fixture!("example");
+
+        let result = count_timelines(&Day07::parse(input).unwrap());
+        assert_eq!(result, BigUint::from(40u64));
+    }
+
+    #[test]
+    fn test_timelines_outgrow_u128() {
+        // Every beam hits a splitter every other row, doubling the count
+        let splits = 130;
+        let width = 2 * splits + 3;
+        let mut input = format!("{}S{}\n", ".".repeat(width / 2), ".".repeat(width / 2));
+        for _ in 0..splits {
+            input += &format!("{}\n{}\n", "^".repeat(width), ".".repeat(width));
+        }
+
+        let result = count_timelines(&Day07::parse(&input).unwrap());
+        assert_eq!(result.to_string(), "1361129467683753853853498429727072845824");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day07::parse("...\n.^.").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (1, 1, 3));
+
+        let err = Day07::parse(".S.\n.v.").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_core::bigint::BigUint;
+use aoc_core::grid::Grid;
+use day_07::{count_timelines, simulate_beams};
+
+fn grid(rows: &[&str]) -> Grid<char> {
+    Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect()).unwrap()
+}
+
+#[test]
+fn test_hand_built_grids() {
+    // Without splitters the beam never divides
+    let straight = grid(&["..S..", ".....", "....."]);
+    assert_eq!(simulate_beams(&straight), 0);
+    assert_eq!(count_timelines(&straight), BigUint::from(1u64));
+
+    // Two splitters side by side share the beam between them
+    let shared = grid(&["..S..", "..^..", ".^.^.", "....."]);
+    assert_eq!(simulate_beams(&shared), 3);
+    assert_eq!(count_timelines(&shared), BigUint::from(4u64));
+}
@@ -0,0 +1,161 @@
+use aoc_core::disjoint_set::DisjointSet;
+use aoc_core::geometry::Point3;
+use aoc_core::parse::{lines, Context};
+use aoc_core::{Answer, Detail, Details, ParseError, Part, Solution};
+
+// Part 1 connects this many of the closest pairs
+pub const NUM_CONNECTIONS: usize = 1000;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input = Vec<Point3>;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_input(input, ctx)
+    }
+
+    fn part1(points: &Self::Input) -> Answer {
+        solve(points, NUM_CONNECTIONS).into()
+    }
+
+    fn part2(points: &Self::Input) -> Answer {
+        solve_part2(points).into()
+    }
+
+    fn solve_detailed(points: &Self::Input, part: Part) -> (Answer, Details) {
+        match (part, last_connection(points)) {
+            (Part::Two, Some((a, b))) => (x_product(a, b).into(), vec![("last_connection", Detail::from([a, b]))]),
+            _ => (Self::solve(points, part), Details::new()),
+        }
+    }
+}
+
+pub fn parse_input(input: &str, ctx: &mut Context) -> Result<Vec<Point3>, ParseError> {
+    let mut points = Vec::new();
+
+    for line in lines(Day08::DAY, input) {
+        if ctx.skip_blank(&line)? {
+            continue;
+        }
+        points.extend(ctx.recover(Point3::from_line(&line))?);
+    }
+
+    Ok(points)
+}
+
+pub fn solve(points: &[Point3], num_connections: usize) -> usize {
+    let n = points.len();
+
+    // Generate all pairs with their distances
+    let mut pairs = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = points[i].distance_squared(&points[j]).expect("32-bit coordinates are close enough");
+            pairs.push((dist, i, j));
+        }
+    }
+
+    // Sort by distance
+    pairs.sort_by_key(|&(dist, _, _)| dist);
+
+    // Connect the closest pairs using Union-Find
+    let mut uf = DisjointSet::new(n);
+    for &(_, i, j) in pairs.iter().take(num_connections) {
+        uf.union(i, j);
+    }
+
+    // Get circuit sizes and find three largest
+    let mut sizes = uf.component_sizes();
+    sizes.sort_by(|a, b| b.cmp(a)); // Sort descending
+
+    // Multiply three largest
+    sizes.iter().take(3).product()
+}
+
+pub fn solve_part2(points: &[Point3]) -> i64 {
+    last_connection(points).map_or(0, |(a, b)| x_product(a, b))
+}
+
+// Shared by part2 and solve_detailed, so text and JSON report the same type
+fn x_product(a: Point3, b: Point3) -> i64 {
+    a.x() as i64 * b.x() as i64
+}
+
+/// The pair whose connection first joins every junction box into one circuit.
+pub fn last_connection(points: &[Point3]) -> Option<(Point3, Point3)> {
+    let n = points.len();
+
+    // Generate all pairs with their distances
+    let mut pairs = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = points[i].distance_squared(&points[j]).expect("32-bit coordinates are close enough");
+            pairs.push((dist, i, j));
+        }
+    }
+
+    // Sort by distance
+    pairs.sort_by_key(|&(dist, _, _)| dist);
+
+    // Connect pairs until all are in one circuit
+    let mut uf = DisjointSet::new(n);
+
+    for &(_, i, j) in &pairs {
+        // Check if all are connected
+        if uf.union(i, j) && uf.components() == 1 {
+            return Some((points[i], points[j]));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    #[test]
+    fn test_example() {
+        let input = fixture!("example");
+
+        let result = solve(&Day08::parse(input).unwrap(), 10);
+        assert_eq!(result, 40);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "162,817,812\n57,618,57\n906,360,560";
+        let points = Day08::parse(input).unwrap();
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0].x(), 162);
+        assert_eq!(points[0].y(), 817);
+        assert_eq!(points[0].z(), 812);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = fixture!("example");
+
+        let result = solve_part2(&Day08::parse(input).unwrap());
+        assert_eq!(result, 25272); // 216 * 117
+
+        let (a, b) = last_connection(&Day08::parse(input).unwrap()).unwrap();
+        assert_eq!((a.x(), b.x()), (216, 117));
+
+        let points = Day08::parse(input).unwrap();
+        let (detailed, _) = Day08::solve_detailed(&points, Part::Two);
+        assert_eq!(detailed, Day08::part2(&points));
+        assert_eq!(detailed.kind(), Day08::part2(&points).kind());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day08::parse("162,817,812\n57,618").unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (8, 2, 7));
+    }
+}
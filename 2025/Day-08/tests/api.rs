@@ -0,0 +1,26 @@
+use aoc_core::fixture;
+use aoc_core::json::Value;
+use aoc_core::parse::Mode;
+use aoc_core::solution::{self, Part};
+use aoc_core::Solution;
+use day_08::{solve, Day08};
+
+#[test]
+fn test_last_connection_detail() {
+    let outcome = solution::run::<Day08>(fixture!("example"), &[Part::Two], Mode::Strict).unwrap();
+    let json = outcome.to_json(2025, 8, &[Part::Two]).remove(0);
+    assert_eq!(
+        json.get("details").unwrap().to_string(),
+        r#"{"last_connection":[[216,146,977],[117,168,530]]}"#
+    );
+    assert_eq!(json.get("answer"), Some(&Value::from("25272")));
+}
+
+#[test]
+fn test_solve_connection_counts() {
+    let points = Day08::parse(fixture!("example")).unwrap();
+    // No connections leaves 20 circuits of one box each
+    assert_eq!(solve(&points, 0), 1);
+    // Enough to join everything leaves a single circuit
+    assert_eq!(solve(&points, 190), 20);
+}
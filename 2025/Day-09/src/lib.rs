@@ -0,0 +1,473 @@
+use aoc_core::geometry::{BoundingBox, Point2 as Point};
+use aoc_core::parse::{lines, Context};
+use aoc_core::{Answer, Detail, Details, ParseError, Part, Solution};
+use std::fmt;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Point>;
+
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+        parse_input(input, ctx)
+    }
+
+    fn part1(points: &Self::Input) -> Answer {
+        find_largest_rectangle(points).into()
+    }
+
+    fn part2(points: &Self::Input) -> Answer {
+        find_largest_rectangle_part2(points).into()
+    }
+
+    fn solve_detailed(points: &Self::Input, part: Part) -> (Answer, Details) {
+        let best = match part {
+            Part::One => largest_rectangle(points),
+            Part::Two => largest_rectangle_part2(points),
+        };
+        match best {
+            Some(rect) => (rect.area.into(), vec![("corners", Detail::from(rect.corners))]),
+            None => (0i64.into(), Details::new()),
+        }
+    }
+}
+
+/// A rectangle between two red tiles at opposite corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub corners: [Point; 2],
+    pub area: i64,
+}
+
+pub fn parse_input(input: &str, ctx: &mut Context) -> Result<Vec<Point>, ParseError> {
+    let mut points = Vec::new();
+
+    for line in lines(Day09::DAY, input) {
+        if ctx.skip_blank(&line)? {
+            continue;
+        }
+        points.extend(ctx.recover(Point::from_line(&line))?);
+    }
+
+    Ok(points)
+}
+
+pub fn find_largest_rectangle(points: &[Point]) -> i64 {
+    largest_rectangle(points).map_or(0, |rect| rect.area)
+}
+
+pub fn largest_rectangle(points: &[Point]) -> Option<Rectangle> {
+    let n = points.len();
+    let mut best: Option<Rectangle> = None;
+
+    // Try all pairs of points as opposite corners
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let p1 = points[i];
+            let p2 = points[j];
+
+            let area = rectangle_area(p1, p2);
+
+            if best.is_none_or(|rect| area > rect.area) {
+                best = Some(Rectangle { corners: [p1, p2], area });
+            }
+        }
+    }
+
+    best
+}
+
+/// The number of tiles in the rectangle with opposite corners `p1` and `p2`,
+/// including the corner tiles.
+fn rectangle_area(p1: Point, p2: Point) -> i64 {
+    BoundingBox::from_points(&[p1, p2]).map_or(0, |bounds| bounds.volume() as i64)
+}
+
+/// Whether `p` is inside the polygon or on its boundary, by ray casting.
+pub fn point_in_or_on_polygon(p: Point, polygon: &[Point]) -> bool {
+    let n = polygon.len();
+
+    // Check if point is on an edge
+    for i in 0..n {
+        let p1 = polygon[i];
+        let p2 = polygon[(i + 1) % n];
+
+        if is_on_segment(p, p1, p2) {
+            return true;
+        }
+    }
+
+    // Ray casting algorithm for interior points
+    let mut inside = false;
+    let mut j = n - 1;
+
+    for i in 0..n {
+        let pi = polygon[i];
+        let pj = polygon[j];
+
+        if (pi.y() > p.y()) != (pj.y() > p.y()) {
+            // Use i64 to avoid overflow with large coordinates
+            let x_intersect = (pj.x() as i64 - pi.x() as i64) * (p.y() as i64 - pi.y() as i64) / (pj.y() as i64 - pi.y() as i64) + pi.x() as i64;
+            if (p.x() as i64) < x_intersect {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+
+    inside
+}
+
+// Check if point p is on line segment from p1 to p2
+fn is_on_segment(p: Point, p1: Point, p2: Point) -> bool {
+    // Check if p is collinear with p1 and p2 using i64 to avoid overflow
+    let cross = (p.y() as i64 - p1.y() as i64) * (p2.x() as i64 - p1.x() as i64)
+              - (p.x() as i64 - p1.x() as i64) * (p2.y() as i64 - p1.y() as i64);
+    if cross != 0 {
+        return false;
+    }
+
+    // Check if p is within the bounding box of the segment
+    let min_x = p1.x().min(p2.x());
+    let max_x = p1.x().max(p2.x());
+    let min_y = p1.y().min(p2.y());
+    let max_y = p1.y().max(p2.y());
+
+    p.x() >= min_x && p.x() <= max_x && p.y() >= min_y && p.y() <= max_y
+}
+
+pub fn find_largest_rectangle_part2(points: &[Point]) -> i64 {
+    largest_rectangle_part2(points).map_or(0, |rect| rect.area)
+}
+
+/// The largest rectangle with red corners that lies entirely on red or green
+/// tiles, i.e. inside the polygon the red tiles trace.
+pub fn largest_rectangle_part2(points: &[Point]) -> Option<Rectangle> {
+    let n = points.len();
+    let mut max_area = 0i64;
+    let mut best_corners = None;
+
+    // Compute polygon bounding box for quick rejection
+    let polygon_box = BoundingBox::from_points(points)?;
+
+    // Try all pairs of red tiles as opposite corners
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let p1 = points[i];
+            let p2 = points[j];
+
+            // Get rectangle bounds
+            let min_x = p1.x().min(p2.x());
+            let max_x = p1.x().max(p2.x());
+            let min_y = p1.y().min(p2.y());
+            let max_y = p1.y().max(p2.y());
+
+            let area = rectangle_area(p1, p2);
+
+            // Skip if this rectangle can't beat current max
+            if area <= max_area {
+                continue;
+            }
+
+            // Quick rejection: if rectangle extends beyond polygon bounds, skip
+            if !polygon_box.contains(&Point::new(min_x, min_y)) ||
+               !polygon_box.contains(&Point::new(max_x, max_y)) {
+                continue;
+            }
+
+            // For small rectangles, check all points
+            // For larger rectangles, check a sample of points along edges
+            const SMALL_AREA_THRESHOLD: i64 = 10000000; // 10 million - check more exhaustively
+
+            let valid = if area <= SMALL_AREA_THRESHOLD {
+                // Check all points for small rectangles
+                let mut all_valid = true;
+                'outer: for x in min_x..=max_x {
+                    for y in min_y..=max_y {
+                        if !point_in_or_on_polygon(Point::new(x, y), points) {
+                            all_valid = false;
+                            break 'outer;
+                        }
+                    }
+                }
+                all_valid
+            } else {
+                // For large rectangles, sample points more densely along edges
+                // Check the 2 implied corners first
+                let corner3 = Point::new(min_x, max_y);
+                let corner4 = Point::new(max_x, min_y);
+
+                if !point_in_or_on_polygon(corner3, points) ||
+                   !point_in_or_on_polygon(corner4, points) {
+                    false
+                } else {
+                    // Sample 100 points along the perimeter and check interior samples
+                    let num_samples = 100;
+                    let mut all_valid = true;
+
+                    // Sample points along all 4 edges
+                    for i in 0..num_samples {
+                        let t = i as f64 / num_samples as f64;
+
+                        // Top edge
+                        let x = (min_x as f64 + t * (max_x - min_x) as f64) as i32;
+                        if !point_in_or_on_polygon(Point::new(x, max_y), points) {
+                            all_valid = false;
+                            break;
+                        }
+
+                        // Bottom edge
+                        let x = (min_x as f64 + t * (max_x - min_x) as f64) as i32;
+                        if !point_in_or_on_polygon(Point::new(x, min_y), points) {
+                            all_valid = false;
+                            break;
+                        }
+
+                        // Left edge
+                        let y = (min_y as f64 + t * (max_y - min_y) as f64) as i32;
+                        if !point_in_or_on_polygon(Point::new(min_x, y), points) {
+                            all_valid = false;
+                            break;
+                        }
+
+                        // Right edge
+                        let y = (min_y as f64 + t * (max_y - min_y) as f64) as i32;
+                        if !point_in_or_on_polygon(Point::new(max_x, y), points) {
+                            all_valid = false;
+                            break;
+                        }
+                    }
+
+                    // Also check some interior points
+                    if all_valid {
+                        for i in 1..10 {
+                            for j in 1..10 {
+                                let x = min_x + (max_x - min_x) * i / 10;
+                                let y = min_y + (max_y - min_y) * j / 10;
+                                if !point_in_or_on_polygon(Point::new(x, y), points) {
+                                    all_valid = false;
+                                    break;
+                                }
+                            }
+                            if !all_valid {
+                                break;
+                            }
+                        }
+                    }
+
+                    all_valid
+                }
+            };
+
+            if valid && area > max_area {
+                max_area = area;
+                best_corners = Some([p1, p2]);
+            }
+        }
+    }
+
+    best_corners.map(|corners| Rectangle { corners, area: max_area })
+}
+
+/// Which way a polygon's vertices run, with y increasing downwards as in
+/// the puzzle's drawings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl fmt::Display for Orientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Orientation::Clockwise => write!(f, "clockwise"),
+            Orientation::CounterClockwise => write!(f, "counter-clockwise"),
+        }
+    }
+}
+
+/// Statistics for the polygon traced by the red tiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonStats {
+    pub vertices: usize,
+    /// `None` if the polygon encloses no area.
+    pub orientation: Option<Orientation>,
+    /// Twice the enclosed area, which is whole for lattice vertices.
+    pub double_area: u128,
+    pub perimeter: f64,
+    /// Lattice points on the edges, i.e. tiles the loop passes through.
+    pub boundary_points: u128,
+    /// Lattice points strictly inside, by Pick's theorem.
+    pub interior_points: u128,
+    pub bounds: BoundingBox<2>,
+}
+
+impl PolygonStats {
+    pub fn area(&self) -> f64 {
+        self.double_area as f64 / 2.0
+    }
+
+    /// Tiles on or inside the loop, i.e. the red and green tiles.
+    pub fn tiles(&self) -> u128 {
+        self.boundary_points + self.interior_points
+    }
+
+    /// The fraction of the bounding box's tiles that are red or green.
+    pub fn fill_ratio(&self) -> f64 {
+        self.tiles() as f64 / self.bounds.volume() as f64
+    }
+}
+
+/// Twice the signed area of the polygon by the shoelace formula, positive
+/// when the vertices run clockwise.
+pub fn signed_double_area(points: &[Point]) -> i128 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (p, q) = (points[i], points[(i + 1) % n]);
+            p.x() as i128 * q.y() as i128 - q.x() as i128 * p.y() as i128
+        })
+        .sum()
+}
+
+pub fn orientation(points: &[Point]) -> Option<Orientation> {
+    match signed_double_area(points).signum() {
+        1 => Some(Orientation::Clockwise),
+        -1 => Some(Orientation::CounterClockwise),
+        _ => None,
+    }
+}
+
+pub fn perimeter(points: &[Point]) -> f64 {
+    let n = points.len();
+    (0..n).map(|i| points[i].euclidean(&points[(i + 1) % n])).sum()
+}
+
+/// The number of lattice points on the polygon's edges. An edge covers
+/// gcd(|dx|, |dy|) of them, not counting its start.
+pub fn boundary_points(points: &[Point]) -> u128 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (p, q) = (points[i], points[(i + 1) % n]);
+            gcd(p.x().abs_diff(q.x()) as u128, p.y().abs_diff(q.y()) as u128)
+        })
+        .sum()
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Computes `PolygonStats` for a simple polygon, or `None` if there are no
+/// vertices.
+pub fn analyze(points: &[Point]) -> Option<PolygonStats> {
+    let bounds = BoundingBox::from_points(points)?;
+    let double_area = signed_double_area(points).unsigned_abs();
+    let boundary_points = boundary_points(points);
+
+    // Pick's theorem: A = I + B/2 - 1, so 2I = 2A - B + 2
+    let interior_points = (double_area + 2).saturating_sub(boundary_points) / 2;
+
+    Some(PolygonStats {
+        vertices: points.len(),
+        orientation: orientation(points),
+        double_area,
+        perimeter: perimeter(points),
+        boundary_points,
+        interior_points,
+        bounds,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::fixture;
+
+    #[test]
+    fn test_example() {
+        let input = fixture!("example");
+
+        let points = Day09::parse(input).unwrap();
+        assert_eq!(points.len(), 8);
+
+        let result = find_largest_rectangle(&points);
+        assert_eq!(result, 50);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "7,1\n11,1\n11,7";
+        let points = Day09::parse(input).unwrap();
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0], Point::new(7, 1));
+        assert_eq!(points[1], Point::new(11, 1));
+        assert_eq!(points[2], Point::new(11, 7));
+    }
+
+    #[test]
+    fn test_rectangle_area() {
+        let points = vec![Point::new(2, 5), Point::new(11, 1)];
+        let result = find_largest_rectangle(&points);
+        // Width: |11 - 2| + 1 = 10 (inclusive)
+        // Height: |5 - 1| + 1 = 5 (inclusive)
+        // Area: 10 * 5 = 50
+        assert_eq!(result, 50);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = fixture!("example");
+
+        let points = Day09::parse(input).unwrap();
+        assert_eq!(points.len(), 8);
+
+        let result = find_largest_rectangle_part2(&points);
+        assert_eq!(result, 24);
+
+        let best = largest_rectangle_part2(&points).unwrap();
+        assert_eq!(best.corners, [Point::new(9, 5), Point::new(2, 3)]);
+    }
+
+    #[test]
+    fn test_analyze_example() {
+        let points = Day09::parse(fixture!("example")).unwrap();
+        let stats = analyze(&points).unwrap();
+
+        assert_eq!(stats.vertices, 8);
+        assert_eq!(stats.orientation, Some(Orientation::Clockwise));
+        assert_eq!((stats.area(), stats.perimeter), (30.0, 30.0));
+        assert_eq!((stats.boundary_points, stats.interior_points), (30, 16));
+        assert_eq!(stats.tiles(), 46);
+        assert_eq!(stats.bounds.lengths(), [10, 7]);
+        assert!((stats.fill_ratio() - 46.0 / 70.0).abs() < 1e-12);
+
+        let reversed: Vec<Point> = points.into_iter().rev().collect();
+        assert_eq!(orientation(&reversed), Some(Orientation::CounterClockwise));
+    }
+
+    #[test]
+    fn test_analyze_diagonal_edges() {
+        // A right triangle with legs of 4 has 3 lattice points strictly inside
+        let points = vec![Point::new(0, 0), Point::new(4, 0), Point::new(0, 4)];
+        let stats = analyze(&points).unwrap();
+        assert_eq!((stats.double_area, stats.boundary_points, stats.interior_points), (16, 12, 3));
+        assert!((stats.perimeter - (8.0 + 32f64.sqrt())).abs() < 1e-12);
+
+        assert_eq!(analyze(&[]), None);
+        assert_eq!(orientation(&points[..2]), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day09::parse("7,1\n11;1").unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (9, 2, 5));
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_core::fixture;
+use aoc_core::geometry::Point2 as Point;
+use aoc_core::parse::Mode;
+use aoc_core::solution::{self, Part};
+use aoc_core::Solution;
+use day_09::{largest_rectangle, point_in_or_on_polygon, Day09};
+
+#[test]
+fn test_corners_detail() {
+    let outcome = solution::run::<Day09>(fixture!("example"), &Part::BOTH, Mode::Strict).unwrap();
+    let json: Vec<String> = outcome
+        .to_json(2025, 9, &Part::BOTH)
+        .iter()
+        .map(|part| part.get("details").unwrap().to_string())
+        .collect();
+    assert_eq!(json, vec![r#"{"corners":[[11,1],[2,5]]}"#, r#"{"corners":[[9,5],[2,3]]}"#]);
+}
+
+#[test]
+fn test_point_in_or_on_polygon() {
+    let points = Day09::parse(fixture!("example")).unwrap();
+    assert!(point_in_or_on_polygon(Point::new(7, 1), &points));
+    assert!(point_in_or_on_polygon(Point::new(9, 2), &points));
+    assert!(point_in_or_on_polygon(Point::new(5, 4), &points));
+    assert!(!point_in_or_on_polygon(Point::new(3, 1), &points));
+    assert!(!point_in_or_on_polygon(Point::new(12, 4), &points));
+}
+
+#[test]
+fn test_degenerate_inputs() {
+    assert_eq!(largest_rectangle(&[]), None);
+    assert_eq!(largest_rectangle(&[Point::new(3, 3)]), None);
+    assert_eq!(Day09::solve(&Vec::new(), Part::Two).kind(), "i64");
+}
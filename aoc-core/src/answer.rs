@@ -0,0 +1,151 @@
+use crate::bigint::BigUint;
+use std::fmt;
+
+/// A puzzle answer.
+///
+/// Answers computed in a wider type than they need, such as a `u128` total
+/// or a `BigUint` product, are stored in the narrowest unsigned variant that
+/// holds them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Wide(u128),
+    Big(BigUint),
+}
+
+impl Answer {
+    /// The name of the answer's type, as reported in JSON output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Signed(_) => "i64",
+            Answer::Unsigned(_) => "u64",
+            Answer::Wide(_) => "u128",
+            Answer::Big(_) => "big",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Wide(value) => write!(f, "{}", value),
+            Answer::Big(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match u64::try_from(value) {
+            Ok(value) => Answer::Unsigned(value),
+            Err(_) => Answer::Wide(value),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        match value.to_u128() {
+            Some(value) => value.into(),
+            None => Answer::Big(value),
+        }
+    }
+}
+
+/// A fact about how an answer was reached, such as the corners of the best
+/// rectangle, reported alongside it in JSON output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Detail {
+    Int(i64),
+    List(Vec<Detail>),
+}
+
+/// Named details of one answer.
+pub type Details = Vec<(&'static str, Detail)>;
+
+impl From<i32> for Detail {
+    fn from(value: i32) -> Self {
+        Detail::Int(value.into())
+    }
+}
+
+impl From<i64> for Detail {
+    fn from(value: i64) -> Self {
+        Detail::Int(value)
+    }
+}
+
+impl<T: Into<Detail>, const N: usize> From<[T; N]> for Detail {
+    fn from(items: [T; N]) -> Self {
+        Detail::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-12i32).to_string(), "-12");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(3i32), Answer::Signed(3));
+        assert_eq!(Answer::from(3usize), Answer::Unsigned(3));
+    }
+
+    #[test]
+    fn test_narrowing() {
+        assert_eq!(Answer::from(7u128), Answer::Unsigned(7));
+        assert_eq!(Answer::from(1u128 << 64), Answer::Wide(1 << 64));
+        assert_eq!(Answer::from(BigUint::from(9u64)), Answer::Unsigned(9));
+
+        let big = BigUint::from(u128::MAX) + &BigUint::from(1u64);
+        let answer = Answer::from(big);
+        assert_eq!(answer.kind(), "big");
+        assert_eq!(answer.to_string(), "340282366920938463463374607431768211456");
+    }
+
+    #[test]
+    fn test_detail_from_array() {
+        let detail = Detail::from([[1, 2], [3, 4]]);
+        let pair = |a, b| Detail::List(vec![Detail::Int(a), Detail::Int(b)]);
+        assert_eq!(detail, Detail::List(vec![pair(1, 2), pair(3, 4)]));
+    }
+}
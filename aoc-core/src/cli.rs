@@ -0,0 +1,242 @@
+//! Argument handling shared by the day binaries.
+
+use crate::parse::{Context, Mode, ParseError};
+use crate::solution::{self, Part, Solution};
+use crate::source::InputSource;
+use std::env;
+use std::process;
+use std::str::FromStr;
+
+const USAGE: &str = "[PATH | -] [--input-str TEXT] [--lenient] [--format text|json]";
+
+/// How results are printed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Prose for people.
+    #[default]
+    Text,
+    /// One JSON object per part, one per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(format!("format must be text or json, got '{}'", other)),
+        }
+    }
+}
+
+/// Input options accepted by every day binary:
+/// `[PATH | -] [--input-str TEXT] [--lenient] [--format text|json]`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct InputArgs {
+    pub source: Option<InputSource>,
+    pub mode: Mode,
+    pub format: Format,
+}
+
+impl InputArgs {
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        InputArgs::parse_with(args, |_, _| Ok(false))
+    }
+
+    /// Like `parse`, but first offers each `--` option to `extra`, which
+    /// returns whether it took the option and pulls any value it needs from
+    /// the remaining arguments. This is how a day adds options of its own.
+    pub fn parse_with<I, F>(args: I, mut extra: F) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+        F: FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>,
+    {
+        let mut parsed = InputArgs::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let source = match arg.as_str() {
+                "--lenient" => {
+                    parsed.mode = Mode::Lenient;
+                    continue;
+                }
+                "--format" => {
+                    parsed.format = args.next().ok_or("missing value for --format")?.parse()?;
+                    continue;
+                }
+                "--input-str" => {
+                    let text = args.next().ok_or("missing value for --input-str")?;
+                    InputSource::Inline(text)
+                }
+                "-" => InputSource::Stdin,
+                _ if arg.starts_with("--") => {
+                    if extra(&arg, &mut args)? {
+                        continue;
+                    }
+                    return Err(format!("unknown option '{}'", arg));
+                }
+                _ => InputSource::from_arg(&arg),
+            };
+            if parsed.source.is_some() {
+                return Err("only one input may be given".to_string());
+            }
+            parsed.source = Some(source);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Parses the process arguments, exiting with usage on error.
+    pub fn from_env() -> Self {
+        InputArgs::from_env_with("", |_, _| Ok(false))
+    }
+
+    /// Parses the process arguments with a day's own options, as in
+    /// `parse_with`. `usage` describes those options for the usage line.
+    pub fn from_env_with<F>(usage: &str, extra: F) -> Self
+    where
+        F: FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>,
+    {
+        let mut args = env::args();
+        let program = args.next().unwrap_or_default();
+        InputArgs::parse_with(args, extra).unwrap_or_else(|message| usage_error(&program, usage, &message))
+    }
+
+    /// Parses the process arguments for a binary with subcommands. A first
+    /// argument naming one of `commands` is returned along with the options
+    /// that follow it, which may include the day's own as in `parse_with`;
+    /// `usage` describes those for the subcommands' usage line.
+    pub fn from_env_with_command<'a, F>(commands: &[&'a str], usage: &str, extra: F) -> (Option<&'a str>, Self)
+    where
+        F: FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>,
+    {
+        let mut args = env::args().peekable();
+        let program = args.next().unwrap_or_default();
+        let command = args.peek().and_then(|arg| commands.iter().copied().find(|command| command == arg));
+        if command.is_some() {
+            args.next();
+        }
+        let parsed = InputArgs::parse_with(args, extra).unwrap_or_else(|message| {
+            let usage = format!("\n       {} {{{}}} {} {}", program, commands.join("|"), USAGE, usage);
+            usage_error(&program, &usage, &message)
+        });
+        (command, parsed)
+    }
+}
+
+fn usage_error(program: &str, usage: &str, message: &str) -> ! {
+    eprintln!("error: {}", message);
+    eprintln!("Usage: {} {} {}", program, USAGE, usage);
+    process::exit(2);
+}
+
+/// Reads and parses the input for `S`, reporting skipped lines on stderr.
+/// Exits the process if the input can't be read or parsed.
+pub fn load<S: Solution>(args: &InputArgs) -> S::Input {
+    let (source, input) = read::<S>(args);
+
+    let mut ctx = Context::new(args.mode);
+    let parsed = S::parse_with(&input, &mut ctx).unwrap_or_else(|err| exit_with(err));
+    report_skipped(&source, ctx.skipped());
+
+    parsed
+}
+
+/// Solves both parts of `S` and prints them as JSON lines, for
+/// `--format json`. Exits the process if the input can't be read or parsed.
+pub fn print_json<S: Solution>(args: &InputArgs) {
+    let (source, input) = read::<S>(args);
+
+    let outcome = solution::run::<S>(&input, &Part::BOTH, args.mode).unwrap_or_else(|err| exit_with(err));
+    report_skipped(&source, &outcome.skipped);
+
+    for line in outcome.to_json(S::YEAR, S::DAY, &Part::BOTH) {
+        println!("{}", line);
+    }
+}
+
+fn read<S: Solution>(args: &InputArgs) -> (InputSource, String) {
+    let source = InputSource::resolve(args.source.clone(), S::YEAR, S::DAY);
+    match source.read() {
+        Ok(input) => (source, input),
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", source, err);
+            process::exit(1);
+        }
+    }
+}
+
+fn exit_with(err: ParseError) -> ! {
+    eprintln!("error: {}", err.diagnostic());
+    process::exit(1);
+}
+
+fn report_skipped(source: &InputSource, skipped: &[ParseError]) {
+    if skipped.is_empty() {
+        return;
+    }
+    eprintln!("warning: skipped {} line(s) of {}", skipped.len(), source);
+    for err in skipped {
+        eprintln!("  line {}: {:?}: {}", err.line, err.text, err.message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<InputArgs, String> {
+        InputArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_sources() {
+        assert_eq!(parse(&[]), Ok(InputArgs::default()));
+        assert_eq!(parse(&["-"]).unwrap().source, Some(InputSource::Stdin));
+        assert_eq!(
+            parse(&["in.txt", "--lenient"]),
+            Ok(InputArgs {
+                source: Some(InputSource::File(PathBuf::from("in.txt"))),
+                mode: Mode::Lenient,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(parse(&["--format", "json"]).unwrap().format, Format::Json);
+        assert_eq!(
+            parse(&["--input-str", "1-2"]).unwrap().source,
+            Some(InputSource::Inline("1-2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse(&["--input-str"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn test_extra_options() {
+        let mut until = None;
+        let args = ["in.txt", "--until", "5", "--lenient"].map(String::from);
+        let parsed = InputArgs::parse_with(args, |arg, rest| match arg {
+            "--until" => {
+                until = rest.next();
+                Ok(true)
+            }
+            _ => Ok(false),
+        });
+        assert_eq!(parsed.unwrap().mode, Mode::Lenient);
+        assert_eq!(until.as_deref(), Some("5"));
+
+        let parsed = InputArgs::parse_with(["--other".to_string()], |_, _| Ok(false));
+        assert_eq!(parsed, Err("unknown option '--other'".to_string()));
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Disjoint sets over the indices `0..len()`, merged by size with path
+/// compression.
+///
+/// The number of components and the size of the largest one are kept up to
+/// date on every union, so reading them is O(1).
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    // Each component's members form a cycle through `next`, so two
+    // components join by swapping one link each
+    next: Vec<usize>,
+    components: usize,
+    largest: usize,
+}
+
+impl DisjointSet {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            next: (0..n).collect(),
+            components: n,
+            largest: n.min(1),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Adds a new singleton set and returns its index.
+    pub fn add(&mut self) -> usize {
+        let index = self.parent.len();
+        self.parent.push(index);
+        self.size.push(1);
+        self.next.push(index);
+        self.components += 1;
+        self.largest = self.largest.max(1);
+        index
+    }
+
+    /// The root of the set holding `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point everything on the way straight at the root
+        let mut current = x;
+        while current != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets holding `x` and `y`, returning false if they were
+    /// already the same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut root_x, mut root_y) = (self.find(x), self.find(y));
+        if root_x == root_y {
+            return false;
+        }
+
+        if self.size[root_x] < self.size[root_y] {
+            std::mem::swap(&mut root_x, &mut root_y);
+        }
+        self.parent[root_y] = root_x;
+        self.size[root_x] += self.size[root_y];
+        self.next.swap(root_x, root_y);
+
+        self.components -= 1;
+        self.largest = self.largest.max(self.size[root_x]);
+        true
+    }
+
+    pub fn same(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// The size of the set holding `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The size of the largest set, or 0 if there are none.
+    pub fn largest(&self) -> usize {
+        self.largest
+    }
+
+    /// The members of the set holding `x`, starting with `x`.
+    pub fn members(&self, x: usize) -> Vec<usize> {
+        let mut members = vec![x];
+        let mut current = self.next[x];
+        while current != x {
+            members.push(current);
+            current = self.next[current];
+        }
+        members
+    }
+
+    /// The size of every set, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&i| self.parent[i] == i)
+            .map(|i| self.size[i])
+            .collect()
+    }
+}
+
+/// A disjoint set whose unions can be undone, for offline algorithms that
+/// explore and then backtrack.
+///
+/// There's no path compression, since that couldn't be undone cheaply, so
+/// `find` is O(log n) from union by size alone.
+#[derive(Debug, Clone)]
+pub struct RollbackDisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+    largest: usize,
+    // The root attached by each union and the largest size before it
+    history: Vec<(usize, usize)>,
+}
+
+impl RollbackDisjointSet {
+    pub fn new(n: usize) -> Self {
+        RollbackDisjointSet {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+            largest: n.min(1),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut root_x, mut root_y) = (self.find(x), self.find(y));
+        if root_x == root_y {
+            return false;
+        }
+
+        if self.size[root_x] < self.size[root_y] {
+            std::mem::swap(&mut root_x, &mut root_y);
+        }
+        self.history.push((root_y, self.largest));
+        self.parent[root_y] = root_x;
+        self.size[root_x] += self.size[root_y];
+
+        self.components -= 1;
+        self.largest = self.largest.max(self.size[root_x]);
+        true
+    }
+
+    pub fn same(&self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    pub fn size_of(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    pub fn largest(&self) -> usize {
+        self.largest
+    }
+
+    /// A point to `rollback` to later.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes the most recent successful union, returning false if there
+    /// was none.
+    pub fn undo(&mut self) -> bool {
+        let Some((child, largest)) = self.history.pop() else {
+            return false;
+        };
+        let root = self.parent[child];
+        self.parent[child] = child;
+        self.size[root] -= self.size[child];
+        self.components += 1;
+        self.largest = largest;
+        true
+    }
+
+    /// Undoes every union made since `snapshot`.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
+
+/// A disjoint set over arbitrary keys, which are mapped to indices as they
+/// are first seen.
+#[derive(Debug, Clone)]
+pub struct KeyedDisjointSet<K> {
+    sets: DisjointSet,
+    index: HashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K: Hash + Eq + Clone> KeyedDisjointSet<K> {
+    pub fn new() -> Self {
+        KeyedDisjointSet {
+            sets: DisjointSet::new(0),
+            index: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Adds `key` as a singleton set if it's new, returning its index.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&index) = self.index.get(&key) {
+            return index;
+        }
+        let index = self.sets.add();
+        self.index.insert(key.clone(), index);
+        self.keys.push(key);
+        index
+    }
+
+    /// Merges the sets holding `a` and `b`, adding either key if it's new.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// The representative key of the set holding `key`.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = *self.index.get(key)?;
+        let root = self.sets.find(index);
+        Some(&self.keys[root])
+    }
+
+    pub fn same(&mut self, a: &K, b: &K) -> bool {
+        match (self.index.get(a), self.index.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.same(a, b),
+            _ => false,
+        }
+    }
+
+    pub fn size_of(&mut self, key: &K) -> usize {
+        self.index.get(key).map_or(0, |&index| self.sets.size_of(index))
+    }
+
+    pub fn components(&self) -> usize {
+        self.sets.components()
+    }
+
+    pub fn largest(&self) -> usize {
+        self.sets.largest()
+    }
+
+    /// The keys in the set holding `key`, starting with `key`.
+    pub fn members(&self, key: &K) -> Vec<&K> {
+        self.index.get(key).map_or_else(Vec::new, |&index| {
+            self.sets.members(index).into_iter().map(|i| &self.keys[i]).collect()
+        })
+    }
+}
+
+impl<K: Hash + Eq + Clone> Default for KeyedDisjointSet<K> {
+    fn default() -> Self {
+        KeyedDisjointSet::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mut members: Vec<usize>) -> Vec<usize> {
+        members.sort();
+        members
+    }
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = DisjointSet::new(6);
+        assert_eq!((sets.components(), sets.largest()), (6, 1));
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.same(0, 3));
+        assert!(!sets.same(0, 4));
+
+        assert_eq!((sets.components(), sets.largest()), (3, 4));
+        assert_eq!(sets.size_of(2), 4);
+        assert_eq!(sorted(sets.members(3)), vec![0, 1, 2, 3]);
+        assert_eq!(sets.members(5), vec![5]);
+        assert_eq!(sorted(sets.component_sizes()), vec![1, 1, 4]);
+
+        let added = sets.add();
+        assert_eq!((added, sets.components()), (6, 4));
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // Deep enough to overflow the stack with a recursive find
+        let n = 1_000_000;
+        let mut sets = DisjointSet::new(n);
+        for i in 1..n {
+            sets.parent[i - 1] = i;
+        }
+        assert_eq!(sets.find(0), n - 1);
+        assert_eq!(sets.parent[n / 2], n - 1);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut sets = RollbackDisjointSet::new(5);
+        sets.union(0, 1);
+        let snapshot = sets.snapshot();
+
+        sets.union(2, 3);
+        sets.union(1, 3);
+        assert!(!sets.union(0, 2));
+        assert_eq!((sets.components(), sets.largest()), (2, 4));
+
+        assert!(sets.undo());
+        assert!(!sets.same(0, 2));
+        assert_eq!((sets.components(), sets.largest()), (3, 2));
+
+        sets.rollback(snapshot);
+        assert!(!sets.same(2, 3));
+        assert!(sets.same(0, 1));
+        assert_eq!((sets.components(), sets.largest()), (4, 2));
+
+        sets.rollback(0);
+        assert!(!sets.undo());
+        assert_eq!(sets.size_of(0), 1);
+    }
+
+    #[test]
+    fn test_keyed() {
+        let mut sets = KeyedDisjointSet::new();
+        sets.union("a", "b");
+        sets.union("c", "d");
+        sets.insert("e");
+        sets.union("b", "d");
+
+        assert_eq!((sets.len(), sets.components(), sets.largest()), (5, 2, 4));
+        assert!(sets.same(&"a", &"c"));
+        assert!(!sets.same(&"a", &"e"));
+        assert!(!sets.same(&"a", &"z"));
+        let root = *sets.find(&"d").unwrap();
+        assert_eq!(sets.find(&"a"), Some(&root));
+        assert_eq!(sets.find(&"z"), None);
+
+        let mut members = sets.members(&"c");
+        members.sort();
+        assert_eq!(members, vec![&"a", &"b", &"c", &"d"]);
+        assert_eq!(sets.size_of(&"e"), 1);
+    }
+}
@@ -0,0 +1,403 @@
+use crate::answer::Detail;
+use crate::parse::{Line, ParseError};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// An integer type usable as a coordinate.
+///
+/// Operators on points and vectors work in the coordinate type itself, so
+/// they overflow exactly like the plain integers would. Distances widen each
+/// coordinate to `i128` first and can't overflow.
+pub trait Coord:
+    Copy
+    + Ord
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    fn widen(self) -> i128;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            fn widen(self) -> i128 {
+                self as i128
+            }
+        })*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A point in `N`-dimensional integer space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<const N: usize, T = i32> {
+    pub coords: [T; N],
+}
+
+/// The displacement between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector<const N: usize, T = i32> {
+    pub coords: [T; N],
+}
+
+/// A point on a 2D integer grid.
+pub type Point2<T = i32> = Point<2, T>;
+
+/// A point in 3D integer space.
+pub type Point3<T = i32> = Point<3, T>;
+
+impl<T: Coord> Point<2, T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { coords: [x, y] }
+    }
+}
+
+impl<T: Coord> Point<3, T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point { coords: [x, y, z] }
+    }
+
+    pub fn z(&self) -> T {
+        self.coords[2]
+    }
+}
+
+impl<const N: usize, T: Coord> Point<N, T> {
+    pub fn x(&self) -> T {
+        self.coords[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.coords[1]
+    }
+
+    /// Parses a whole line as `N` comma-separated coordinates.
+    pub fn from_line(line: &Line) -> Result<Self, ParseError> {
+        let fields: [&str; N] = line.fields(',')?;
+        let mut coords = [T::default(); N];
+        for (i, (coord, field)) in coords.iter_mut().zip(fields).enumerate() {
+            *coord = line.parse_at(field, &coord_name(i))?;
+        }
+        Ok(Point { coords })
+    }
+
+    /// Per-axis differences, widened so they can't overflow.
+    fn deltas(&self, other: &Self) -> impl Iterator<Item = u128> {
+        self.coords
+            .into_iter()
+            .zip(other.coords)
+            .map(|(a, b)| a.widen().abs_diff(b.widen()))
+    }
+
+    pub fn manhattan(&self, other: &Self) -> u128 {
+        self.deltas(other).sum()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> u128 {
+        self.deltas(other).max().unwrap_or(0)
+    }
+
+    /// The squared Euclidean distance, which always fits for coordinates of
+    /// up to 32 bits. `None` if points with 64-bit coordinates are too far
+    /// apart for a `u128`.
+    pub fn distance_squared(&self, other: &Self) -> Option<u128> {
+        self.deltas(other)
+            .try_fold(0u128, |sum, d| sum.checked_add(d.checked_mul(d)?))
+    }
+
+    pub fn euclidean(&self, other: &Self) -> f64 {
+        self.deltas(other)
+            .map(|d| (d as f64).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+fn coord_name(i: usize) -> String {
+    match i {
+        0..=2 => format!("{} coordinate", ["x", "y", "z"][i]),
+        _ => format!("coordinate {}", i + 1),
+    }
+}
+
+impl<const N: usize, T: Coord> Vector<N, T> {
+    pub fn zero() -> Self {
+        Vector { coords: [T::default(); N] }
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for Point<N, T> {
+    fn from(coords: [T; N]) -> Self {
+        Point { coords }
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for Vector<N, T> {
+    fn from(coords: [T; N]) -> Self {
+        Vector { coords }
+    }
+}
+
+// Applies `op` coordinate by coordinate
+fn zip_with<const N: usize, T: Copy>(a: [T; N], b: [T; N], op: impl Fn(T, T) -> T) -> [T; N] {
+    std::array::from_fn(|i| op(a[i], b[i]))
+}
+
+impl<const N: usize, T: Coord> Sub for Point<N, T> {
+    type Output = Vector<N, T>;
+
+    fn sub(self, other: Self) -> Vector<N, T> {
+        Vector { coords: zip_with(self.coords, other.coords, T::sub) }
+    }
+}
+
+impl<const N: usize, T: Coord> Add<Vector<N, T>> for Point<N, T> {
+    type Output = Self;
+
+    fn add(self, v: Vector<N, T>) -> Self {
+        Point { coords: zip_with(self.coords, v.coords, T::add) }
+    }
+}
+
+impl<const N: usize, T: Coord> Sub<Vector<N, T>> for Point<N, T> {
+    type Output = Self;
+
+    fn sub(self, v: Vector<N, T>) -> Self {
+        Point { coords: zip_with(self.coords, v.coords, T::sub) }
+    }
+}
+
+impl<const N: usize, T: Coord> AddAssign<Vector<N, T>> for Point<N, T> {
+    fn add_assign(&mut self, v: Vector<N, T>) {
+        *self = *self + v;
+    }
+}
+
+impl<const N: usize, T: Coord> SubAssign<Vector<N, T>> for Point<N, T> {
+    fn sub_assign(&mut self, v: Vector<N, T>) {
+        *self = *self - v;
+    }
+}
+
+impl<const N: usize, T: Coord> Add for Vector<N, T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vector { coords: zip_with(self.coords, other.coords, T::add) }
+    }
+}
+
+impl<const N: usize, T: Coord> Sub for Vector<N, T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vector { coords: zip_with(self.coords, other.coords, T::sub) }
+    }
+}
+
+impl<const N: usize, T: Coord> Mul<T> for Vector<N, T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Vector { coords: self.coords.map(|c| c * k) }
+    }
+}
+
+impl<const N: usize, T: Coord + Neg<Output = T>> Neg for Vector<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vector { coords: self.coords.map(T::neg) }
+    }
+}
+
+/// The smallest axis-aligned box holding a set of points, bounds inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<const N: usize, T = i32> {
+    pub min: Point<N, T>,
+    pub max: Point<N, T>,
+}
+
+impl<const N: usize, T: Coord> BoundingBox<N, T> {
+    /// The bounding box of `points`, or `None` if there are none.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Point<N, T>>) -> Option<Self>
+    where
+        T: 'a,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        let mut bounds = BoundingBox { min: first, max: first };
+        for point in points {
+            bounds.include(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the box to hold `point`.
+    pub fn include(&mut self, point: &Point<N, T>) {
+        for i in 0..N {
+            self.min.coords[i] = self.min.coords[i].min(point.coords[i]);
+            self.max.coords[i] = self.max.coords[i].max(point.coords[i]);
+        }
+    }
+
+    pub fn contains(&self, point: &Point<N, T>) -> bool {
+        (0..N).all(|i| (self.min.coords[i]..=self.max.coords[i]).contains(&point.coords[i]))
+    }
+
+    /// The number of integer positions along each axis.
+    pub fn lengths(&self) -> [u128; N] {
+        std::array::from_fn(|i| self.min.coords[i].widen().abs_diff(self.max.coords[i].widen()) + 1)
+    }
+
+    /// The number of integer positions inside the box, saturating at
+    /// `u128::MAX`.
+    pub fn volume(&self) -> u128 {
+        self.lengths().into_iter().fold(1, u128::saturating_mul)
+    }
+}
+
+impl<const N: usize, T: Into<Detail>> From<Point<N, T>> for Detail {
+    fn from(p: Point<N, T>) -> Self {
+        Detail::from(p.coords)
+    }
+}
+
+/// Writes the coordinates comma-separated, the same form `FromStr` reads.
+impl<const N: usize, T: fmt::Display> fmt::Display for Point<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, coord) in self.coords.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", coord)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePointError;
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid point")
+    }
+}
+
+impl std::error::Error for ParsePointError {}
+
+impl<const N: usize, T: Coord> FromStr for Point<N, T> {
+    type Err = ParsePointError;
+
+    /// Parses exactly `N` comma-separated integer coordinates.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coords = [T::default(); N];
+        let mut parts = s.trim().split(',');
+        for coord in coords.iter_mut() {
+            let part = parts.next().ok_or(ParsePointError)?;
+            *coord = part.trim().parse().map_err(|_| ParsePointError)?;
+        }
+        if parts.next().is_some() {
+            return Err(ParsePointError);
+        }
+        Ok(Point { coords })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_point2() {
+        assert_eq!("7,1".parse(), Ok(Point2::new(7, 1)));
+        assert_eq!(" 11 , 7 ".parse(), Ok(Point2::new(11, 7)));
+        assert!("7".parse::<Point2>().is_err());
+        assert!("7,1,2".parse::<Point2>().is_err());
+        assert!("".parse::<Point2>().is_err());
+        assert_eq!(Point2::new(-3, 4).to_string(), "-3,4");
+    }
+
+    #[test]
+    fn test_parse_point3() {
+        assert_eq!("162,817,812".parse(), Ok(Point3::new(162, 817, 812)));
+        assert!("162,817".parse::<Point3>().is_err());
+        assert!("a,b,c".parse::<Point3>().is_err());
+        assert_eq!("1,2,3,4".parse(), Ok(Point::<4, u8>::from([1, 2, 3, 4])));
+    }
+
+    #[test]
+    fn test_from_line() {
+        let line = Line { day: 8, number: 4, text: "162, 817,812" };
+        assert_eq!(Point3::from_line(&line), Ok(Point3::new(162, 817, 812)));
+
+        let line = Line { day: 8, number: 4, text: "162,8a7,812" };
+        let err = Point3::<i32>::from_line(&line).unwrap_err();
+        assert_eq!((err.column, err.width), (5, 3));
+        assert_eq!(err.message, "invalid y coordinate '8a7'");
+
+        let line = Line { day: 9, number: 1, text: "7,1,2" };
+        assert!(Point2::<i32>::from_line(&line).is_err());
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        let v = b - a;
+        assert_eq!(v, Vector::from([3, -4]));
+        assert_eq!(a + v, b);
+        assert_eq!(b - v, a);
+        assert_eq!(v * 2 + -v, v);
+        assert_eq!(v - v, Vector::zero());
+
+        let mut p = a;
+        p += v;
+        p -= v * 2;
+        assert_eq!(p, Point2::new(-2, 6));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point3::new(162, 817, 812);
+        let b = Point3::new(425, 690, 689);
+        assert_eq!(a.distance_squared(&b), Some(263 * 263 + 127 * 127 + 123 * 123));
+        assert_eq!(a.manhattan(&b), 263 + 127 + 123);
+        assert_eq!(a.chebyshev(&b), 263);
+        assert_eq!(Point2::new(0, 0).euclidean(&Point2::new(3, -4)), 5.0);
+
+        // The extremes of i32 and i64 are further apart than either type can hold
+        let (lo, hi) = (Point2::new(i32::MIN, i32::MIN), Point2::new(i32::MAX, i32::MAX));
+        let span = u32::MAX as u128;
+        assert_eq!(lo.manhattan(&hi), 2 * span);
+        assert_eq!(lo.distance_squared(&hi), Some(2 * span * span));
+        let (lo, hi) = (Point2::new(i64::MIN, 0), Point2::new(i64::MAX, 0));
+        assert_eq!(lo.chebyshev(&hi), u64::MAX as u128);
+        assert_eq!(lo.distance_squared(&hi), Some((u64::MAX as u128).pow(2)));
+        // Past that, distances no longer fit rather than all comparing equal
+        let (lo, hi) = (Point2::new(i64::MIN, i64::MIN), Point2::new(i64::MAX, i64::MAX));
+        assert_eq!(lo.distance_squared(&hi), None);
+        assert_eq!(Point2::new(0, 0).distance_squared(&Point2::new(0, i64::MAX)), Some((i64::MAX as u128).pow(2)));
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point2::new(7, 1), Point2::new(11, 7), Point2::new(2, 3)];
+        let bounds = BoundingBox::from_points(&points).unwrap();
+        assert_eq!(bounds.min, Point2::new(2, 1));
+        assert_eq!(bounds.max, Point2::new(11, 7));
+        assert_eq!(bounds.lengths(), [10, 7]);
+        assert_eq!(bounds.volume(), 70);
+        assert!(bounds.contains(&Point2::new(2, 7)));
+        assert!(!bounds.contains(&Point2::new(1, 7)));
+        assert_eq!(BoundingBox::<2>::from_points(&[]), None);
+    }
+}
@@ -0,0 +1,357 @@
+use crate::parse::{end_of_input, lines, Context, Line, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the 4 orthogonally adjacent positions, as `(row, col)` deltas.
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the 8 positions surrounding a cell, as `(row, col)` deltas.
+#[rustfmt::skip]
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1), // top-left, top, top-right
+    (0, -1),           (0, 1),  // left, right
+    (1, -1),  (1, 0),  (1, 1),  // bottom-left, bottom, bottom-right
+];
+
+/// Iterates over the in-bounds 4-neighbours of `(row, col)` in a
+/// `rows` x `cols` grid.
+pub fn neighbors_4(
+    row: usize,
+    col: usize,
+    rows: usize,
+    cols: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    offsets(&NEIGHBORS_4, row, col, rows, cols)
+}
+
+/// Iterates over the in-bounds 8-neighbours of `(row, col)` in a
+/// `rows` x `cols` grid.
+pub fn neighbors_8(
+    row: usize,
+    col: usize,
+    rows: usize,
+    cols: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    offsets(&NEIGHBORS_8, row, col, rows, cols)
+}
+
+fn offsets(
+    deltas: &'static [(isize, isize)],
+    row: usize,
+    col: usize,
+    rows: usize,
+    cols: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    deltas.iter().filter_map(move |&(dr, dc)| {
+        let new_row = row.checked_add_signed(dr)?;
+        let new_col = col.checked_add_signed(dc)?;
+        (new_row < rows && new_col < cols).then_some((new_row, new_col))
+    })
+}
+
+/// A rectangular grid stored row-major in one flat `Vec`.
+///
+/// Positions are `(row, col)` pairs with `(0, 0)` in the top-left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `rows` x `cols` grid with every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { cells: vec![value; rows * cols], rows, cols }
+    }
+
+    /// Builds a grid from its rows, or `None` if they differ in width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        let height = rows.len();
+        Some(Grid { cells: rows.into_iter().flatten().collect(), rows: height, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        Some(&mut self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    /// The cells of row `row`, left to right.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The cells of column `col`, top to bottom.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {} out of range for width {}", col, self.cols);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a size of 0, and a grid without columns has no rows worth seeing
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// Every position and its cell in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn neighbors_4(&self, (row, col): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        neighbors_4(row, col, self.rows, self.cols)
+    }
+
+    pub fn neighbors_8(&self, (row, col): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        neighbors_8(row, col, self.rows, self.cols)
+    }
+
+    /// The first position holding `value`, in row-major order.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    /// Every position holding `value`, in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), rows: self.rows, cols: self.cols }
+    }
+
+    /// The grid flipped over its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.cols).flat_map(|col| self.column(col).cloned()).collect();
+        Grid { cells, rows: self.cols, cols: self.rows }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!("position {:?} out of range for {}x{} grid", pos, self.rows, self.cols)
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {:?} out of range for {}x{} grid", pos, rows, cols))
+    }
+}
+
+/// One line per row with the cells written back to back, so a parsed
+/// character grid prints as it was read.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a rectangular grid of characters, one row per line.
+///
+/// Every character must be one of `allowed` and every row must have the same
+/// width as the first. In lenient mode rows breaking either rule are skipped.
+pub fn parse_char_grid(
+    day: u8,
+    input: &str,
+    allowed: &[char],
+    ctx: &mut Context,
+) -> Result<Grid<char>, ParseError> {
+    let mut grid: Vec<Vec<char>> = Vec::new();
+
+    for line in lines(day, input) {
+        if ctx.skip_blank(&line)? {
+            continue;
+        }
+        let width = grid.first().map(Vec::len);
+        if let Some(row) = ctx.recover(parse_row(&line, allowed, width))? {
+            grid.push(row);
+        }
+    }
+
+    if grid.is_empty() {
+        return Err(end_of_input(day, input, "grid is empty"));
+    }
+
+    Ok(Grid::from_rows(grid).expect("rows were checked against the first"))
+}
+
+fn parse_row(line: &Line, allowed: &[char], width: Option<usize>) -> Result<Vec<char>, ParseError> {
+    if let Some((i, c)) = line.text.char_indices().find(|(_, c)| !allowed.contains(c)) {
+        let expected: Vec<String> = allowed.iter().map(|c| format!("'{}'", c)).collect();
+        return Err(line.error_at(
+            &line.text[i..i + c.len_utf8()],
+            format!("unexpected '{}', expected one of {}", c, expected.join(", ")),
+        ));
+    }
+
+    let row: Vec<char> = line.text.chars().collect();
+    if let Some(width) = width
+        && row.len() != width
+    {
+        return Err(line.error(
+            row.len().min(width) + 1,
+            1,
+            format!("row is {} cells wide, expected {}", row.len(), width),
+        ));
+    }
+
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corner_has_three_neighbors() {
+        let neighbors: Vec<_> = neighbors_8(0, 0, 3, 3).collect();
+        assert_eq!(neighbors, vec![(0, 1), (1, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn test_center_has_eight_neighbors() {
+        assert_eq!(neighbors_8(1, 1, 3, 3).count(), 8);
+    }
+
+    #[test]
+    fn test_single_row() {
+        let neighbors: Vec<_> = neighbors_8(0, 8, 1, 10).collect();
+        assert_eq!(neighbors, vec![(0, 7), (0, 9)]);
+    }
+
+    #[test]
+    fn test_neighbors_4() {
+        let neighbors: Vec<_> = neighbors_4(0, 1, 2, 3).collect();
+        assert_eq!(neighbors, vec![(0, 0), (0, 2), (1, 1)]);
+    }
+
+    fn sample() -> Grid<u8> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn test_grid_access() {
+        let mut grid = sample();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.get((1, 2)), Some(&6));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+
+        *grid.get_mut((0, 1)).unwrap() = 9;
+        grid[(1, 0)] = 8;
+        assert_eq!(grid[(0, 1)], 9);
+        assert_eq!(grid.row(1), &[8, 5, 6]);
+        assert!(grid.get_mut((5, 5)).is_none());
+        assert!(Grid::from_rows(vec![vec![1], vec![2, 3]]).is_none());
+    }
+
+    #[test]
+    fn test_grid_iterators() {
+        let grid = sample();
+        let rows: Vec<&[u8]> = grid.iter_rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+
+        let columns: Vec<Vec<u8>> = grid.iter_columns().map(|col| col.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+
+        assert_eq!(grid.neighbors_4((1, 1)).count(), 3);
+        assert_eq!(grid.neighbors_8((1, 1)).count(), 5);
+        assert_eq!(grid.find(&5), Some((1, 1)));
+        assert_eq!(grid.find(&7), None);
+    }
+
+    #[test]
+    fn test_grid_transpose() {
+        let grid = sample().transpose();
+        assert_eq!((grid.rows(), grid.cols()), (3, 2));
+        assert_eq!(grid.to_string(), "14\n25\n36\n");
+        assert_eq!(grid.transpose(), sample());
+    }
+
+    #[test]
+    fn test_parse_char_grid() {
+        let grid = parse_char_grid(4, "..@\n@.@\n", &['@', '.'], &mut Context::strict()).unwrap();
+        assert_eq!(grid, Grid::from_rows(vec![vec!['.', '.', '@'], vec!['@', '.', '@']]).unwrap());
+        assert_eq!(grid.to_string(), "..@\n@.@\n");
+    }
+
+    #[test]
+    fn test_parse_char_grid_errors() {
+        let err = parse_char_grid(4, "..@\n@x@", &['@', '.'], &mut Context::strict()).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (4, 2, 2));
+        assert_eq!(err.message, "unexpected 'x', expected one of '@', '.'");
+
+        let err = parse_char_grid(4, "..@\n@.", &['@', '.'], &mut Context::strict()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "row is 2 cells wide, expected 3");
+
+        let err = parse_char_grid(4, "", &['@', '.'], &mut Context::strict()).unwrap_err();
+        assert_eq!(err.message, "grid is empty");
+    }
+
+    #[test]
+    fn test_parse_char_grid_lenient() {
+        let mut ctx = Context::lenient();
+        let grid = parse_char_grid(4, "..@\n\n@x@\n@.\n@@@", &['@', '.'], &mut ctx).unwrap();
+        assert_eq!(grid.to_string(), "..@\n@@@\n");
+
+        let skipped: Vec<_> = ctx.skipped().iter().map(|err| err.line).collect();
+        assert_eq!(skipped, vec![2, 3, 4]);
+    }
+}
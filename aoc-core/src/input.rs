@@ -0,0 +1,193 @@
+use crate::grid::Grid;
+use crate::parse::{lines, Line, ParseError};
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Converts `\r\n` and lone `\r` line endings to `\n` and drops a leading
+/// byte order mark, so a checkout with Windows line endings parses the same.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    if !input.contains('\r') {
+        return Cow::Borrowed(input);
+    }
+    Cow::Owned(input.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+/// A run of non-blank lines, along with the blank lines just before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub blanks_before: Vec<Line<'a>>,
+    pub lines: Vec<Line<'a>>,
+}
+
+/// Splits `input` into sections separated by one or more blank lines. Lines
+/// of only whitespace count as blank.
+pub fn sections(day: u8, input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut blanks = Vec::new();
+    let mut current: Option<Section> = None;
+
+    for line in lines(day, input) {
+        if line.text.trim().is_empty() {
+            sections.extend(current.take());
+            blanks.push(line);
+            continue;
+        }
+        current
+            .get_or_insert_with(|| Section {
+                blanks_before: std::mem::take(&mut blanks),
+                lines: Vec::new(),
+            })
+            .lines
+            .push(line);
+    }
+    sections.extend(current);
+
+    sections
+}
+
+/// Every integer on `line`, in order. A `-` is a minus sign unless it
+/// follows a digit, so `3-5` reads as 3 and 5 but `x=-5` as -5.
+pub fn ints<T: FromStr>(line: &Line) -> Result<Vec<T>, ParseError> {
+    let text = line.text;
+    let bytes = text.as_bytes();
+    let mut ints = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        ints.push(line.parse_at(&text[start..i], "integer")?);
+    }
+
+    Ok(ints)
+}
+
+/// Splits `line` once on `separator` into a trimmed key and value.
+pub fn key_value<'a>(line: &Line<'a>, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let text = line.text;
+    let (key, value) = text.split_once(separator).ok_or_else(|| {
+        line.error_at(text, format!("expected 'key{}value', found '{}'", separator, text.trim()))
+    })?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(line.error(1, 1, format!("missing key before '{}'", separator)));
+    }
+    Ok((key, value.trim()))
+}
+
+/// Lays `rows` out as a grid, padding short rows with spaces. Inputs
+/// aligned in fixed-width columns then read the same whether or not an
+/// editor has stripped the trailing spaces.
+pub fn padded_grid(rows: &[&str]) -> Grid<char> {
+    let width = rows.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+    let rows = rows
+        .iter()
+        .map(|row| {
+            let mut cells: Vec<char> = row.chars().collect();
+            cells.resize(width, ' ');
+            cells
+        })
+        .collect();
+    Grid::from_rows(rows).expect("rows were padded to the same width")
+}
+
+/// The runs of columns holding anything but spaces, left to right. Columns
+/// of nothing but spaces separate one block from the next.
+pub fn column_blocks(grid: &Grid<char>) -> Vec<RangeInclusive<usize>> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+
+    for col in 0..=grid.cols() {
+        let blank = col == grid.cols() || grid.column(col).all(|&c| c == ' ');
+        match (blank, start) {
+            (false, None) => start = Some(col),
+            (true, Some(first)) => {
+                blocks.push(first..=col - 1);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line { day: 1, number: 1, text }
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb\rc\n"), "a\nb\nc\n");
+        assert_eq!(normalize("\u{feff}a\n"), "a\n");
+        assert!(matches!(normalize("a\nb"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "3-5\n10-14\n\n \n1\n5\n\n\n";
+        let sections = sections(5, input);
+        let texts: Vec<Vec<&str>> = sections
+            .iter()
+            .map(|section| section.lines.iter().map(|line| line.text).collect())
+            .collect();
+        assert_eq!(texts, vec![vec!["3-5", "10-14"], vec!["1", "5"]]);
+
+        let blanks: Vec<usize> = sections[1].blanks_before.iter().map(|line| line.number).collect();
+        assert_eq!(blanks, vec![3, 4]);
+        assert!(sections[0].blanks_before.is_empty());
+    }
+
+    #[test]
+    fn test_sections_crlf() {
+        let unix = sections(5, "1\n2\n\n3\n");
+        let windows = sections(5, "1\r\n2\r\n\r\n3\r\n");
+        assert_eq!(windows.len(), 2);
+        assert_eq!(unix, windows);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i64>(&line("p=0,-4 v=3,-3")), Ok(vec![0, -4, 3, -3]));
+        assert_eq!(ints::<u64>(&line("3-5, 10-14")), Ok(vec![3, 5, 10, 14]));
+        assert_eq!(ints::<i32>(&line("no numbers - here")), Ok(vec![]));
+
+        let err = ints::<u8>(&line("12 300")).unwrap_err();
+        assert_eq!((err.column, err.width), (4, 3));
+        assert_eq!(err.message, "invalid integer '300'");
+    }
+
+    #[test]
+    fn test_key_value() {
+        assert_eq!(key_value(&line("Register A: 729"), ":"), Ok(("Register A", "729")));
+        assert_eq!(key_value(&line("a -> b"), "->"), Ok(("a", "b")));
+        assert!(key_value(&line("a b"), ":").is_err());
+        assert!(key_value(&line(": b"), ":").is_err());
+    }
+
+    #[test]
+    fn test_fixed_width_columns() {
+        let grid = padded_grid(&["123 328", " 45 64", "*   +  "]);
+        assert_eq!((grid.rows(), grid.cols()), (3, 7));
+        assert_eq!(grid.row(1), &[' ', '4', '5', ' ', '6', '4', ' ']);
+        assert_eq!(column_blocks(&grid), vec![0..=2, 4..=6]);
+        assert!(column_blocks(&padded_grid(&["   "])).is_empty());
+    }
+}
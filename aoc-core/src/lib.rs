@@ -0,0 +1,18 @@
+//! Shared helpers for the Advent of Code solvers.
+
+pub mod answer;
+pub mod bigint;
+pub mod cli;
+pub mod disjoint_set;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod parse;
+pub mod ranges;
+pub mod solution;
+pub mod source;
+
+pub use answer::{Answer, Detail, Details};
+pub use parse::ParseError;
+pub use solution::{Part, Solution};
@@ -0,0 +1,316 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A problem with a puzzle input, pointing at the offending line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// Number of characters the error spans, at least 1.
+    pub width: usize,
+    /// The offending line, empty when the input ended too early.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Renders the error with the offending line and a caret under the
+    /// problem, e.g.
+    ///
+    /// ```text
+    /// invalid distance 'x5'
+    ///  --> day 1, line 3, column 2
+    ///   |
+    /// 3 | Lx5
+    ///   |  ^^
+    /// ```
+    pub fn diagnostic(&self) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let text: String = self.text.chars().map(|c| if c == '\t' { ' ' } else { c }).collect();
+        format!(
+            "{}\n{}--> day {}, line {}, column {}\n{} |\n{} | {}\n{} | {}{}",
+            self.message,
+            gutter,
+            self.day,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            text,
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A single numbered line of a day's input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error spanning `width` characters from the 1-based `column`.
+    pub fn error(&self, column: usize, width: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: column.max(1),
+            width: width.max(1),
+            text: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error spanning `part`, which should be a slice of this line's text.
+    /// Anything else is reported just past the end of the line.
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        let offset = if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            offset
+        } else {
+            self.text.len()
+        };
+        let column = self.text[..offset].chars().count() + 1;
+        self.error(column, part.chars().count(), message)
+    }
+
+    /// Splits the line on `separator` into exactly `N` trimmed fields.
+    pub fn fields<const N: usize>(&self, separator: char) -> Result<[&'a str; N], ParseError> {
+        let mut fields = [""; N];
+        let mut parts = self.text.split(separator);
+        for (i, field) in fields.iter_mut().enumerate() {
+            *field = match parts.next() {
+                Some(part) => part.trim(),
+                None => {
+                    return Err(self.error_at(
+                        &self.text[self.text.len()..],
+                        format!("expected {} fields separated by '{}', found {}", N, separator, i),
+                    ));
+                }
+            };
+        }
+        if let Some(extra) = parts.next() {
+            return Err(self.error_at(
+                extra,
+                format!("expected {} fields separated by '{}', found more", N, separator),
+            ));
+        }
+        Ok(fields)
+    }
+
+    /// Parses `part` (a slice of this line) as a `T`, naming it `what` in
+    /// the error message on failure.
+    pub fn parse_at<T: FromStr>(&self, part: &str, what: &str) -> Result<T, ParseError> {
+        part.parse()
+            .map_err(|_| self.error_at(part, format!("invalid {} '{}'", what, part)))
+    }
+}
+
+/// Iterates over the numbered lines of `input`, ignoring blank lines at the
+/// very end.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    let count = input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .last()
+        .map_or(0, |(i, _)| i + 1);
+
+    input.lines().take(count).enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+/// How a parser treats lines it does not recognise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Any unrecognised or blank line is an error.
+    #[default]
+    Strict,
+    /// Unrecognised and blank lines are skipped and recorded.
+    Lenient,
+}
+
+/// Parsing state threaded through a day's parser.
+#[derive(Debug, Default)]
+pub struct Context {
+    mode: Mode,
+    skipped: Vec<ParseError>,
+}
+
+impl Context {
+    pub fn new(mode: Mode) -> Self {
+        Context {
+            mode,
+            skipped: Vec::new(),
+        }
+    }
+
+    pub fn strict() -> Self {
+        Context::new(Mode::Strict)
+    }
+
+    pub fn lenient() -> Self {
+        Context::new(Mode::Lenient)
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Passes `Ok` values through. An error is returned as-is in strict
+    /// mode, while lenient mode records it as a skipped line and yields
+    /// `None`.
+    pub fn recover<T>(&mut self, result: Result<T, ParseError>) -> Result<Option<T>, ParseError> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if self.mode == Mode::Lenient => {
+                self.skipped.push(err);
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns whether `line` is blank and should be skipped, which is only
+    /// allowed in lenient mode.
+    pub fn skip_blank(&mut self, line: &Line) -> Result<bool, ParseError> {
+        if !line.text.trim().is_empty() {
+            return Ok(false);
+        }
+        self.recover::<()>(Err(line.error(1, 1, "unexpected blank line")))?;
+        Ok(true)
+    }
+
+    /// The lines skipped so far, in input order.
+    pub fn skipped(&self) -> &[ParseError] {
+        &self.skipped
+    }
+
+    pub fn into_skipped(self) -> Vec<ParseError> {
+        self.skipped
+    }
+}
+
+/// An error for input that stops before something it requires.
+pub fn end_of_input(day: u8, input: &str, message: impl Into<String>) -> ParseError {
+    ParseError {
+        day,
+        line: input.lines().count() + 1,
+        column: 1,
+        width: 1,
+        text: String::new(),
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_are_numbered() {
+        let numbered: Vec<_> = lines(1, "a\r\nb\n\nc").map(|l| (l.number, l.text)).collect();
+        assert_eq!(numbered, vec![(1, "a"), (2, "b"), (3, ""), (4, "c")]);
+    }
+
+    #[test]
+    fn test_lines_ignore_trailing_blanks() {
+        let numbered: Vec<_> = lines(1, "a\n\nb\n\n  \n").map(|l| l.number).collect();
+        assert_eq!(numbered, vec![1, 2, 3]);
+        assert_eq!(lines(1, "\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_context_strict() {
+        let mut ctx = Context::strict();
+        let line = Line { day: 1, number: 2, text: "" };
+        assert!(ctx.skip_blank(&line).is_err());
+        assert_eq!(ctx.recover(Ok(3)), Ok(Some(3)));
+        assert!(ctx.recover::<i32>(Err(line.error(1, 1, "bad"))).is_err());
+        assert!(ctx.skipped().is_empty());
+    }
+
+    #[test]
+    fn test_context_lenient() {
+        let mut ctx = Context::lenient();
+        let blank = Line { day: 1, number: 2, text: " " };
+        let junk = Line { day: 1, number: 3, text: "junk" };
+        assert_eq!(ctx.skip_blank(&blank), Ok(true));
+        assert_eq!(ctx.skip_blank(&junk), Ok(false));
+        assert_eq!(ctx.recover::<i32>(Err(junk.error(1, 4, "bad"))), Ok(None));
+
+        let skipped: Vec<_> = ctx.skipped().iter().map(|err| err.line).collect();
+        assert_eq!(skipped, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_error_at_points_at_slice() {
+        let line = lines(1, "L68\nLx5").nth(1).unwrap();
+        let err = line.parse_at::<i32>(&line.text[1..], "distance").unwrap_err();
+        assert_eq!((err.day, err.line, err.column, err.width), (1, 2, 2, 2));
+        assert_eq!(err.text, "Lx5");
+        assert_eq!(err.message, "invalid distance 'x5'");
+        assert_eq!(err.to_string(), "day 1, line 2, column 2: invalid distance 'x5'");
+    }
+
+    #[test]
+    fn test_error_at_foreign_slice() {
+        let line = Line { day: 3, number: 1, text: "abc" };
+        let err = line.error_at("elsewhere", "oops");
+        assert_eq!(err.column, 4);
+    }
+
+    #[test]
+    fn test_fields() {
+        let line = Line { day: 8, number: 1, text: "1, 2,3" };
+        assert_eq!(line.fields::<3>(','), Ok(["1", "2", "3"]));
+
+        let err = line.fields::<2>(',').unwrap_err();
+        assert_eq!((err.column, err.width), (6, 1));
+
+        let err = line.fields::<4>(',').unwrap_err();
+        assert_eq!(err.column, 7);
+        assert_eq!(err.message, "expected 4 fields separated by ',', found 3");
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let line = Line { day: 1, number: 12, text: "Lx5" };
+        let err = line.error(2, 2, "invalid distance 'x5'");
+        assert_eq!(
+            err.diagnostic(),
+            "invalid distance 'x5'\n  --> day 1, line 12, column 2\n   |\n12 | Lx5\n   |  ^^"
+        );
+    }
+
+    #[test]
+    fn test_end_of_input() {
+        let err = end_of_input(5, "1-2\n3-4\n", "missing ingredient IDs");
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, ""));
+    }
+}
@@ -0,0 +1,313 @@
+use crate::parse::{lines, Context, Line, ParseError};
+use std::fmt;
+
+/// An integer type usable as an interval endpoint.
+pub trait Endpoint: Copy + Ord + fmt::Debug {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_next(self) -> Option<Self>;
+    fn checked_prev(self) -> Option<Self>;
+
+    /// The number of values in `start..=end`.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_endpoint {
+    ($($t:ty),*) => {
+        $(impl Endpoint for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn checked_prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> u128 {
+                (end as i128).abs_diff(start as i128) + 1
+            }
+        })*
+    };
+}
+
+impl_endpoint!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A set of integers stored as sorted inclusive ranges.
+///
+/// The ranges are kept normalised: none are empty, and no two overlap or
+/// touch, so `3-5` and `6-8` are stored as `3-8`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// The normalised ranges in ascending order.
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|&(start, end)| T::count(start, end)).sum()
+    }
+
+    /// Whether `value` is in the set, by binary search.
+    pub fn contains(&self, value: T) -> bool {
+        let after = self.ranges.partition_point(|&(start, _)| start <= value);
+        after > 0 && self.ranges[after - 1].1 >= value
+    }
+
+    /// Adds `start..=end`, doing nothing if `start > end`.
+    pub fn insert(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+
+        // Ranges overlapping or touching the new one, which all merge into it
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.checked_next().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| end.checked_next().is_none_or(|next| s <= next));
+
+        let mut merged = (start, end);
+        if first < last {
+            merged.0 = merged.0.min(self.ranges[first].0);
+            merged.1 = merged.1.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Removes `start..=end`, doing nothing if `start > end`.
+    pub fn remove(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first >= last {
+            return;
+        }
+
+        // Whatever sticks out either side of the removed range survives
+        let mut kept = Vec::with_capacity(2);
+        let (left, right) = (self.ranges[first].0, self.ranges[last - 1].1);
+        if left < start {
+            kept.push((left, start.checked_prev().unwrap()));
+        }
+        if right > end {
+            kept.push((end.checked_next().unwrap(), right));
+        }
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.ranges.iter().copied());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&a), Some(&b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Whichever range ends first can't meet anything else
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for &(start, end) in &other.ranges {
+            difference.remove(start, end);
+        }
+        difference
+    }
+
+    /// Everything in `start..=end` that isn't in the set.
+    pub fn complement(&self, start: T, end: T) -> Self {
+        let mut complement = IntervalSet::new();
+        complement.insert(start, end);
+        complement.difference(self)
+    }
+}
+
+impl<T: Endpoint> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Endpoint> Extend<(T, T)> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = (T, T)>>(&mut self, ranges: I) {
+        for (start, end) in ranges {
+            self.insert(start, end);
+        }
+    }
+}
+
+/// Collects possibly overlapping ranges, such as those from `parse_ranges`.
+impl<T: Endpoint> FromIterator<(T, T)> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+/// Parses one inclusive `a-b` range from `part`, a slice of `line`.
+pub fn parse_range(line: &Line, part: &str) -> Result<(u64, u64), ParseError> {
+    let part = part.trim();
+    let (start, end) = part.split_once('-').ok_or_else(|| {
+        line.error_at(part, format!("expected a range like 'a-b', found '{}'", part))
+    })?;
+    let start: u64 = line.parse_at(start, "range start")?;
+    let end: u64 = line.parse_at(end, "range end")?;
+    if start > end {
+        return Err(line.error_at(part, format!("range start {} is after its end {}", start, end)));
+    }
+    Ok((start, end))
+}
+
+/// Parses inclusive `a-b` ranges separated by commas and/or newlines. A line
+/// may end with a comma, as when a long list is wrapped.
+///
+/// In lenient mode blank lines and malformed entries are skipped.
+pub fn parse_ranges(day: u8, input: &str, ctx: &mut Context) -> Result<Vec<(u64, u64)>, ParseError> {
+    let mut ranges = Vec::new();
+    for line in lines(day, input) {
+        if ctx.skip_blank(&line)? {
+            continue;
+        }
+        let text = line.text.trim_end();
+        for part in text.strip_suffix(',').unwrap_or(text).split(',') {
+            ranges.extend(ctx.recover(parse_range(&line, part))?);
+        }
+    }
+    Ok(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comma_separated() {
+        let ranges = parse_ranges(2, "11-22,95-115,998-1012\n", &mut Context::strict());
+        assert_eq!(ranges, Ok(vec![(11, 22), (95, 115), (998, 1012)]));
+    }
+
+    #[test]
+    fn test_newline_separated() {
+        let ranges = parse_ranges(5, "3-5\n10-14\r\n16-20\n12-18", &mut Context::strict());
+        assert_eq!(ranges, Ok(vec![(3, 5), (10, 14), (16, 20), (12, 18)]));
+    }
+
+    #[test]
+    fn test_wrapped() {
+        let ranges = parse_ranges(2, "11-22,95-115,
+998-1012
+", &mut Context::strict());
+        assert_eq!(ranges, Ok(vec![(11, 22), (95, 115), (998, 1012)]));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = parse_ranges(2, "1-2,,7-8", &mut Context::strict()).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+
+        let err = parse_ranges(2, "1-2\nx-3", &mut Context::strict()).unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (2, 1, 1));
+        assert_eq!(err.message, "invalid range start 'x'");
+
+        let err = parse_ranges(2, "1-2,4-5-6", &mut Context::strict()).unwrap_err();
+        assert_eq!(err.message, "invalid range end '5-6'");
+
+        let err = parse_ranges(2, "9-3", &mut Context::strict()).unwrap_err();
+        assert_eq!(err.message, "range start 9 is after its end 3");
+    }
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet<u64> {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_insert_normalises() {
+        let ranges = set(&[(10, 14), (3, 5), (16, 20), (12, 18)]);
+        assert_eq!(ranges.ranges(), &[(3, 5), (10, 20)]);
+
+        // Touching ranges merge, ones with a gap don't
+        assert_eq!(set(&[(1, 2), (3, 4), (6, 7)]).ranges(), &[(1, 4), (6, 7)]);
+        assert_eq!(set(&[(5, 5), (1, 9), (4, 4)]).ranges(), &[(1, 9)]);
+        assert_eq!(set(&[(u64::MAX - 1, u64::MAX), (0, u64::MAX - 2)]).ranges(), &[(0, u64::MAX)]);
+        assert!(set(&[(5, 4)]).is_empty());
+    }
+
+    #[test]
+    fn test_len_and_contains() {
+        let ranges = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(ranges.len(), 14);
+        let fresh: Vec<u64> = [1, 5, 8, 11, 17, 32].into_iter().filter(|&id| ranges.contains(id)).collect();
+        assert_eq!(fresh, vec![5, 11, 17]);
+
+        assert_eq!(set(&[(0, u64::MAX)]).len(), 1 << 64);
+        assert_eq!(IntervalSet::<i32>::from_iter([(-3, 3)]).len(), 7);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut ranges = set(&[(1, 10), (20, 30)]);
+        ranges.remove(5, 22);
+        assert_eq!(ranges.ranges(), &[(1, 4), (23, 30)]);
+        ranges.remove(24, 25);
+        assert_eq!(ranges.ranges(), &[(1, 4), (23, 23), (26, 30)]);
+        ranges.remove(11, 19);
+        ranges.remove(0, 4);
+        assert_eq!(ranges.ranges(), &[(23, 23), (26, 30)]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (20, 21)]);
+        assert_eq!(a.union(&b).ranges(), &[(1, 15), (20, 21)]);
+        assert_eq!(a.intersection(&b).ranges(), &[(4, 5), (10, 11)]);
+        assert_eq!(a.difference(&b).ranges(), &[(1, 3), (12, 15)]);
+        assert_eq!(a.complement(0, 12).ranges(), &[(0, 0), (6, 9)]);
+        assert_eq!(a.complement(u64::MIN, u64::MAX).complement(u64::MIN, u64::MAX), a);
+    }
+
+    #[test]
+    fn test_lenient() {
+        let mut ctx = Context::lenient();
+        let ranges = parse_ranges(2, "1-2,,x-3\n\n7-8", &mut ctx);
+        assert_eq!(ranges, Ok(vec![(1, 2), (7, 8)]));
+
+        let skipped: Vec<_> = ctx.skipped().iter().map(|err| (err.line, err.column)).collect();
+        assert_eq!(skipped, vec![(1, 5), (1, 6), (2, 1)]);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::answer::{Answer, Detail, Details};
+use crate::input::normalize;
+use crate::json::{self, Value};
+use crate::parse::{Context, Mode, ParseError};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single day's puzzle.
+///
+/// The input is parsed once and shared by both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+
+    /// Parses the input, skipping unrecognised lines if `ctx` is lenient.
+    fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Normalises line endings and parses the input in strict mode.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(&normalize(input), &mut Context::strict())
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Solves `part` along with any details worth reporting about how the
+    /// answer was reached. Days with such details override this so the
+    /// search only runs once.
+    fn solve_detailed(input: &Self::Input, part: Part) -> (Answer, Details) {
+        (Self::solve(input, part), Details::new())
+    }
+}
+
+/// The answers from one run of a day, plus any input lines skipped by a
+/// lenient parse and how long each stage took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub answers: Vec<Answer>,
+    pub skipped: Vec<ParseError>,
+    pub parse_time: Duration,
+    // One entry per solved part, in the same order as `answers`
+    pub part_times: Vec<Duration>,
+    pub details: Vec<Details>,
+}
+
+impl Outcome {
+    /// One JSON object per solved part, as emitted by `--format json`.
+    pub fn to_json(&self, year: u16, day: u8, parts: &[Part]) -> Vec<Value> {
+        parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| {
+                let answer = &self.answers[i];
+                let details = self.details[i]
+                    .iter()
+                    .map(|(name, detail)| (name.to_string(), detail_json(detail)))
+                    .collect();
+                json::object([
+                    ("year", u64::from(year).into()),
+                    ("day", u64::from(day).into()),
+                    ("part", u64::from(part.number()).into()),
+                    // A string, since JSON numbers can't hold every u64
+                    ("answer", answer.to_string().into()),
+                    ("answer_type", answer.kind().into()),
+                    (
+                        "timings",
+                        json::object([
+                            ("parse_ns", (self.parse_time.as_nanos() as u64).into()),
+                            ("solve_ns", (self.part_times[i].as_nanos() as u64).into()),
+                        ]),
+                    ),
+                    ("details", Value::Object(details)),
+                ])
+            })
+            .collect()
+    }
+}
+
+// A JSON number is a double, exact only up to 2^53, so larger integers are
+// written as strings, as answers are
+fn detail_json(detail: &Detail) -> Value {
+    match detail {
+        Detail::Int(value) if value.unsigned_abs() <= 1 << 53 => (*value as f64).into(),
+        Detail::Int(value) => value.to_string().into(),
+        Detail::List(items) => Value::Array(items.iter().map(detail_json).collect()),
+    }
+}
+
+/// Parses `input` once and solves each of `parts` against it.
+pub fn run<S: Solution>(input: &str, parts: &[Part], mode: Mode) -> Result<Outcome, ParseError> {
+    let mut ctx = Context::new(mode);
+    let start = Instant::now();
+    let parsed = S::parse_with(&normalize(input), &mut ctx)?;
+    let parse_time = start.elapsed();
+
+    let mut answers = Vec::with_capacity(parts.len());
+    let mut part_times = Vec::with_capacity(parts.len());
+    let mut details = Vec::with_capacity(parts.len());
+    for &part in parts {
+        let start = Instant::now();
+        let (answer, detail) = S::solve_detailed(&parsed, part);
+        part_times.push(start.elapsed());
+        answers.push(answer);
+        details.push(detail);
+    }
+
+    Ok(Outcome {
+        answers,
+        skipped: ctx.into_skipped(),
+        parse_time,
+        part_times,
+        details,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::lines;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2025;
+        const DAY: u8 = 0;
+
+        type Input = Vec<u64>;
+
+        fn parse_with(input: &str, ctx: &mut Context) -> Result<Self::Input, ParseError> {
+            let mut numbers = Vec::new();
+            for line in lines(Self::DAY, input) {
+                numbers.extend(ctx.recover(line.parse_at::<u64>(line.text, "number"))?);
+            }
+            Ok(numbers)
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<u64>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            input.iter().product::<u64>().into()
+        }
+
+        fn solve_detailed(input: &Self::Input, part: Part) -> (Answer, Details) {
+            (Self::solve(input, part), vec![("count", Detail::Int(input.len() as i64))])
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let outcome = run::<Sum>("2\n3\n4", &Part::BOTH, Mode::Strict).unwrap();
+        assert_eq!(outcome.answers, vec![Answer::Unsigned(9), Answer::Unsigned(24)]);
+        assert!(outcome.skipped.is_empty());
+
+        let outcome = run::<Sum>("2\n3\n4", &[Part::Two], Mode::Strict).unwrap();
+        assert_eq!(outcome.answers, vec![Answer::Unsigned(24)]);
+        assert_eq!(outcome.part_times.len(), 1);
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        let err = run::<Sum>("2\nthree", &Part::BOTH, Mode::Strict).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (0, 2, 1));
+    }
+
+    #[test]
+    fn test_run_lenient() {
+        let outcome = run::<Sum>("2\nthree\n4", &Part::BOTH, Mode::Lenient).unwrap();
+        assert_eq!(outcome.answers, vec![Answer::Unsigned(6), Answer::Unsigned(8)]);
+        assert_eq!(outcome.skipped.len(), 1);
+        assert_eq!(outcome.skipped[0].text, "three");
+    }
+
+    #[test]
+    fn test_outcome_to_json() {
+        let mut outcome = run::<Sum>("2\n3", &[Part::Two], Mode::Strict).unwrap();
+        outcome.parse_time = Duration::from_nanos(10);
+        outcome.part_times = vec![Duration::from_nanos(20)];
+
+        let lines: Vec<String> = outcome.to_json(2025, 0, &[Part::Two]).iter().map(Value::to_string).collect();
+        assert_eq!(
+            lines,
+            vec![
+                r#"{"answer":"6","answer_type":"u64","day":0,"details":{"count":2},"part":2,"#.to_string()
+                    + r#""timings":{"parse_ns":10,"solve_ns":20},"year":2025}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detail_json_is_exact() {
+        let detail = Detail::from([(1i64 << 53) + 1, 1 << 53, -7, i64::MIN]);
+        assert_eq!(
+            detail_json(&detail).to_string(),
+            r#"["9007199254740993",9007199254740992,-7,"-9223372036854775808"]"#
+        );
+    }
+}
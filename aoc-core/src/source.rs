@@ -0,0 +1,151 @@
+use crate::input;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Names a single input file (or `-` for stdin) used instead of the default.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Names a directory laid out like the repository, holding
+/// `<year>/Day-DD/input.txt`, used instead of the checked-in inputs.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// Interprets a command-line argument: `-` is stdin, anything else a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// The source named by `AOC_INPUT`, if set.
+    pub fn from_env() -> Option<Self> {
+        env::var(INPUT_ENV)
+            .ok()
+            .filter(|value| !value.is_empty())
+            .map(|value| InputSource::from_arg(&value))
+    }
+
+    /// Picks the explicit source if given, then `AOC_INPUT`, then the day's
+    /// default input file.
+    pub fn resolve(explicit: Option<InputSource>, year: u16, day: u8) -> Self {
+        explicit
+            .or_else(InputSource::from_env)
+            .unwrap_or_else(|| InputSource::File(default_path(year, day)))
+    }
+
+    /// Reads the whole input, with line endings normalised.
+    pub fn read(&self) -> io::Result<String> {
+        let input = match self {
+            InputSource::File(path) => fs::read_to_string(path)?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            InputSource::Inline(input) => input.clone(),
+        };
+        Ok(input::normalize(&input).into_owned())
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Inline(_) => write!(f, "<inline input>"),
+        }
+    }
+}
+
+/// `<root>/<year>`, where the root is `AOC_INPUT_DIR` if set and the
+/// workspace otherwise, so it doesn't depend on the working directory.
+pub fn year_dir(year: u16) -> PathBuf {
+    let root = env::var_os(INPUT_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/..")));
+
+    root.join(year.to_string())
+}
+
+/// A named input of a day: `input` is the puzzle input,
+/// `<root>/<year>/Day-DD/input.txt`, and any other name is a fixture,
+/// `<root>/<year>/Day-DD/fixtures/<name>.txt`.
+pub fn input_path(year: u16, day: u8, name: &str) -> PathBuf {
+    let dir = year_dir(year).join(format!("Day-{:02}", day));
+    match name {
+        "input" => dir.join("input.txt"),
+        _ => dir.join("fixtures").join(format!("{}.txt", name)),
+    }
+}
+
+/// Includes the calling crate's `fixtures/<name>.txt` as a `&'static str`.
+///
+/// ```ignore
+/// let grid = Day04::parse(aoc_core::fixture!("example")).unwrap();
+/// ```
+#[macro_export]
+macro_rules! fixture {
+    ($name:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/", $name, ".txt"))
+    };
+}
+
+/// The day's puzzle input, `<root>/<year>/Day-DD/input.txt`.
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    input_path(year, day, "input")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(InputSource::from_arg("in.txt"), InputSource::File(PathBuf::from("in.txt")));
+    }
+
+    #[test]
+    fn test_explicit_source_wins() {
+        let inline = InputSource::Inline("L68".to_string());
+        assert_eq!(InputSource::resolve(Some(inline.clone()), 2025, 1), inline);
+        assert_eq!(inline.read().unwrap(), "L68");
+    }
+
+    #[test]
+    fn test_default_path_is_workspace_relative() {
+        if env::var_os(INPUT_DIR_ENV).is_some() {
+            return;
+        }
+        let path = default_path(2025, 7);
+        assert!(path.ends_with("2025/Day-07/input.txt"));
+        assert!(path.parent().unwrap().join("Cargo.toml").exists());
+    }
+
+    #[test]
+    fn test_fixture_path() {
+        let path = input_path(2025, 4, "example-2");
+        assert!(path.ends_with("2025/Day-04/fixtures/example-2.txt"));
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        assert!(source.read().is_err());
+        assert_eq!(source.to_string(), "does/not/exist.txt");
+    }
+}
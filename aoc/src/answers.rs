@@ -0,0 +1,180 @@
+// Known answers for one year, read from `<year>/answers.toml`.
+//
+// Only the small subset of TOML the file needs is understood: comments,
+// `[dayDD.<input>]` tables and `partN = <answer>` keys, where the answer is a
+// quoted string or a bare integer. `<input>` is `input` for the puzzle input
+// or the name of a fixture in `Day-DD/fixtures/`.
+//
+//     [day01.input]
+//     part1 = 1043
+//     part2 = 5963
+
+use aoc_core::Part;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    // (day, input name, part number) -> answer
+    entries: BTreeMap<(u8, String, u8), String>,
+}
+
+impl Answers {
+    /// Loads the answers file, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|err| format!("{}: {}", path.display(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(format!("failed to read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+        let mut table: Option<(u8, String)> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: String| format!("line {}: {}", index + 1, message);
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error(format!("unterminated table header '{}'", line)))?;
+                table = Some(parse_header(header.trim()).map_err(error)?);
+                continue;
+            }
+
+            let (day, input) = table
+                .clone()
+                .ok_or_else(|| error("answer outside of a [dayDD.input] table".to_string()))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected 'partN = answer', found '{}'", line)))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                other => return Err(error(format!("unknown key '{}', expected part1 or part2", other))),
+            };
+            let value = parse_value(value.trim()).map_err(error)?;
+
+            if answers.entries.insert((day, input, part), value).is_some() {
+                return Err(error(format!("duplicate answer for part{}", part)));
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: Part) -> Option<&str> {
+        self.entries
+            .get(&(day, input.to_string(), part.number()))
+            .map(String::as_str)
+    }
+
+    /// The input names with at least one recorded answer for `day`.
+    pub fn inputs(&self, day: u8) -> Vec<&str> {
+        let mut inputs: Vec<&str> = self
+            .entries
+            .keys()
+            .filter(|(d, _, _)| *d == day)
+            .map(|(_, input, _)| input.as_str())
+            .collect();
+        inputs.dedup();
+        inputs
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    // Answers never contain '#', so there's no need to track quoting
+    line.split_once('#').map_or(line, |(before, _)| before)
+}
+
+fn parse_header(header: &str) -> Result<(u8, String), String> {
+    let invalid = || format!("invalid table '[{}]', expected [dayDD.input]", header);
+
+    let (day, input) = header.split_once('.').ok_or_else(invalid)?;
+    let day: u8 = day
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(invalid)?;
+    let is_name = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    if input.is_empty() || !input.chars().all(is_name) {
+        return Err(invalid());
+    }
+
+    Ok((day, input.to_string()))
+}
+
+fn parse_value(value: &str) -> Result<String, String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        return quoted
+            .strip_suffix('"')
+            .filter(|inner| !inner.contains('"'))
+            .map(str::to_string)
+            .ok_or_else(|| format!("invalid string {}", value));
+    }
+
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit() || c == '_') {
+        return Err(format!("invalid answer '{}', expected an integer or a string", value));
+    }
+    Ok(value.replace('_', ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# 2025\n\
+             [day01.input]\n\
+             part1 = 1043\n\
+             part2 = \"5963\" # quoted\n\
+             \n\
+             [day01.example]\n\
+             part1 = 3\n\
+             [day09.input]\n\
+             part2 = 1_452_422_268\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, "input", Part::One), Some("1043"));
+        assert_eq!(answers.get(1, "input", Part::Two), Some("5963"));
+        assert_eq!(answers.get(1, "example", Part::Two), None);
+        assert_eq!(answers.get(9, "input", Part::Two), Some("1452422268"));
+        assert_eq!(answers.inputs(1), vec!["example", "input"]);
+        assert!(answers.inputs(2).is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |text| Answers::parse(text).unwrap_err();
+
+        assert_eq!(error("part1 = 3"), "line 1: answer outside of a [dayDD.input] table");
+        assert!(error("[day26.input]").contains("invalid table"));
+        assert!(error("[day01]").contains("invalid table"));
+        assert!(error("[day01.input]\npart3 = 1").starts_with("line 2: unknown key"));
+        assert!(error("[day01.input]\npart1 = 1\npart1 = 2").contains("duplicate"));
+        assert!(error("[day01.input]\npart1 = abc").contains("invalid answer"));
+        assert!(error("[day01.input]\npart1 = \"abc").contains("invalid string"));
+    }
+
+    #[test]
+    fn test_checked_in_answers_parse() {
+        for year in crate::registry::DAYS.iter().map(|day| day.year) {
+            let path = aoc_core::source::year_dir(year).join(FILE_NAME);
+            Answers::load(&path).unwrap();
+        }
+    }
+}
@@ -0,0 +1,268 @@
+// `aoc bench`: times each day's parse, part 1 and part 2 over many runs and
+// compares the medians against a saved baseline.
+
+use crate::cli::BenchArgs;
+use crate::registry::{self, Day};
+use aoc_core::json::{self, Value};
+use aoc_core::parse::Mode;
+use aoc_core::source;
+use aoc_core::Part;
+use std::collections::BTreeMap;
+use std::fs;
+use std::time::Duration;
+
+const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+// Changes smaller than this are timer noise, whatever the percentage
+const NOISE_FLOOR: Duration = Duration::from_micros(5);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Stats {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            min: sorted[0],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json::object([
+            ("mean_ns", nanos(self.mean)),
+            ("median_ns", nanos(self.median)),
+            ("min_ns", nanos(self.min)),
+            ("stddev_ns", nanos(self.stddev)),
+        ])
+    }
+
+    fn from_json(value: &Value) -> Option<Stats> {
+        let field = |key| value.get(key)?.as_f64().map(|ns| Duration::from_nanos(ns as u64));
+        Some(Stats {
+            mean: field("mean_ns")?,
+            median: field("median_ns")?,
+            min: field("min_ns")?,
+            stddev: field("stddev_ns")?,
+        })
+    }
+}
+
+fn nanos(duration: Duration) -> Value {
+    Value::Number(duration.as_nanos() as f64)
+}
+
+// "2025-01" -> stage name -> stats
+type Results = BTreeMap<String, BTreeMap<String, Stats>>;
+
+fn key(day: &Day) -> String {
+    format!("{}-{:02}", day.year, day.day)
+}
+
+pub fn bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = args
+        .compare
+        .as_ref()
+        .map(|path| {
+            let text = fs::read_to_string(path)
+                .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+            load_baseline(&text).map_err(|err| format!("{}: {}", path.display(), err))
+        })
+        .transpose()?;
+
+    let mut results = Results::new();
+    let mut regressions = 0;
+
+    for day in registry::select(args.year, args.day)? {
+        let path = source::default_path(day.year, day.day);
+        let Ok(input) = fs::read_to_string(&path) else {
+            println!("{} Day {:02}: skipped, no input at {}", day.year, day.day, path.display());
+            continue;
+        };
+
+        let stats = time_day(day, &input, args.warmup, args.iterations)?;
+        for (stage, stats) in STAGES.iter().zip(&stats) {
+            let previous = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(&key(day))?.get(*stage));
+            let comparison = match previous {
+                Some(previous) => {
+                    let change = change(previous.median, stats.median);
+                    let regressed = is_regression(previous.median, stats.median, args.threshold);
+                    regressions += regressed as usize;
+                    format!("  {:+.1}%{}", change, if regressed { " REGRESSION" } else { "" })
+                }
+                None => String::new(),
+            };
+
+            println!(
+                "{} Day {:02} {:<6} mean {:>9}  median {:>9}  min {:>9}  stddev {:>9}{}",
+                day.year,
+                day.day,
+                stage,
+                show(stats.mean),
+                show(stats.median),
+                show(stats.min),
+                show(stats.stddev),
+                comparison
+            );
+        }
+
+        let stages = STAGES.iter().map(|stage| stage.to_string()).zip(stats).collect();
+        results.insert(key(day), stages);
+    }
+
+    if let Some(path) = &args.save {
+        fs::write(path, save_baseline(&results) + "\n")
+            .map_err(|err| format!("failed to write {}: {}", path.display(), err))?;
+        println!("saved baseline to {}", path.display());
+    }
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} stage(s) regressed by more than {}%",
+            regressions, args.threshold
+        ));
+    }
+    Ok(())
+}
+
+fn time_day(day: &Day, input: &str, warmup: usize, iterations: usize) -> Result<[Stats; 3], String> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+
+    for run in 0..warmup + iterations {
+        let outcome = (day.solve)(input, &Part::BOTH, Mode::Strict).map_err(|err| err.diagnostic())?;
+        if run < warmup {
+            continue;
+        }
+        samples[0].push(outcome.parse_time);
+        samples[1].push(outcome.part_times[0]);
+        samples[2].push(outcome.part_times[1]);
+    }
+
+    Ok(samples.map(|samples| Stats::from_samples(&samples)))
+}
+
+// Percentage change from `before` to `after`
+fn change(before: Duration, after: Duration) -> f64 {
+    if before.is_zero() {
+        return 0.0;
+    }
+    (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+}
+
+fn is_regression(before: Duration, after: Duration, threshold: f64) -> bool {
+    after > before && after - before > NOISE_FLOOR && change(before, after) > threshold
+}
+
+fn show(duration: Duration) -> String {
+    format!("{:.1?}", duration)
+}
+
+fn save_baseline(results: &Results) -> String {
+    let days = results
+        .iter()
+        .map(|(key, stages)| {
+            let stages = stages
+                .iter()
+                .map(|(stage, stats)| (stage.clone(), stats.to_json()))
+                .collect();
+            (key.clone(), Value::Object(stages))
+        })
+        .collect();
+    json::object([("version", 1u64.into()), ("results", Value::Object(days))]).to_string()
+}
+
+fn load_baseline(text: &str) -> Result<Results, String> {
+    let value = json::parse(text)?;
+    if value.get("version").and_then(Value::as_f64) != Some(1.0) {
+        return Err("unsupported baseline version".to_string());
+    }
+    let Some(Value::Object(days)) = value.get("results") else {
+        return Err("missing \"results\" object".to_string());
+    };
+
+    let mut results = Results::new();
+    for (key, stages) in days {
+        let Value::Object(stages) = stages else {
+            return Err(format!("results for {} are not an object", key));
+        };
+        let mut parsed = BTreeMap::new();
+        for (stage, stats) in stages {
+            let stats = Stats::from_json(stats)
+                .ok_or_else(|| format!("invalid timings for {} {}", key, stage))?;
+            parsed.insert(stage.clone(), stats);
+        }
+        results.insert(key.clone(), parsed);
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&us| Duration::from_micros(us)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[40, 10, 30, 20]));
+        assert_eq!(stats.mean, Duration::from_micros(25));
+        assert_eq!(stats.median, Duration::from_micros(25));
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.stddev.as_nanos(), 11180);
+
+        let stats = Stats::from_samples(&micros(&[7, 3, 5]));
+        assert_eq!(stats.median, Duration::from_micros(5));
+    }
+
+    #[test]
+    fn test_regression() {
+        let ms = Duration::from_millis;
+        assert!(is_regression(ms(10), ms(12), 10.0));
+        assert!(!is_regression(ms(10), ms(10), 10.0));
+        assert!(!is_regression(ms(12), ms(10), 10.0));
+        assert!(!is_regression(ms(10), ms(11), 10.0));
+        // Tiny stages are all noise
+        let us = Duration::from_micros;
+        assert!(!is_regression(us(1), us(3), 10.0));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let stats = Stats::from_samples(&micros(&[1, 2, 3]));
+        let mut results = Results::new();
+        results.insert("2025-01".to_string(), [("parse".to_string(), stats)].into());
+
+        assert_eq!(load_baseline(&save_baseline(&results)), Ok(results));
+        assert!(load_baseline("{\"version\":2,\"results\":{}}").is_err());
+        assert!(load_baseline("{\"version\":1,\"results\":{\"2025-01\":{\"parse\":{}}}}").is_err());
+    }
+}
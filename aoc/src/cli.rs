@@ -0,0 +1,367 @@
+use aoc_core::cli::Format;
+use aoc_core::parse::Mode;
+use aoc_core::source::InputSource;
+use aoc_core::Part;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: aoc run [--year YEAR] [--day DAY] [--part PART]
+               [--input PATH | --input-str TEXT] [--lenient] [--format FORMAT]
+       aoc verify [--year YEAR] [--day DAY]
+       aoc fixtures [--year YEAR] [--day DAY]
+       aoc bench [--year YEAR] [--day DAY] [--iterations N] [--warmup N]
+                 [--save PATH] [--compare PATH] [--threshold PERCENT]
+
+run     Runs one part, both parts, or every registered day of a year.
+verify  Runs every day against each input with answers in <year>/answers.toml
+        and reports pass, fail or missing; exits non-zero on any failure.
+fixtures
+        Extracts the examples in each day's README.md into Day-DD/fixtures/
+        and adds their stated answers to <year>/answers.toml.
+bench   Times the parse and both parts of each day against its puzzle input.
+
+Options:
+  --year YEAR    Puzzle year (default: latest registered year)
+  --day DAY      Puzzle day; omit to run the whole year
+  --part PART    1 or 2; omit to run both parts
+  --input PATH   Input file, or - for stdin; requires --day
+  --input-str TEXT
+                 Inline puzzle input; requires --day
+  --lenient      Skip blank and unrecognised input lines instead of failing,
+                 and report which lines were skipped
+  --format FORMAT
+                 text (default), or json for one JSON object per part with
+                 the answer, its type, timings and any extra details
+  --iterations N Timed runs per day (default: 20)
+  --warmup N     Untimed runs before timing (default: 3)
+  --save PATH    Write the timings to PATH as a JSON baseline
+  --compare PATH Compare median timings with a saved baseline and exit
+                 non-zero if any stage regressed
+  --threshold PERCENT
+                 Slowdown that counts as a regression (default: 10)
+
+Environment:
+  AOC_INPUT      Input file (or -) for a single day when no input is given
+  AOC_INPUT_DIR  Directory holding <year>/Day-DD/input.txt
+                 (default: the workspace)";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RunArgs {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: Option<InputSource>,
+    pub mode: Mode,
+    pub format: Format,
+}
+
+// The days a command applies to
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub iterations: usize,
+    pub warmup: usize,
+    pub save: Option<PathBuf>,
+    pub compare: Option<PathBuf>,
+    pub threshold: f64,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs {
+            year: None,
+            day: None,
+            iterations: 20,
+            warmup: 3,
+            save: None,
+            compare: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(Selection),
+    Fixtures(Selection),
+    Bench(BenchArgs),
+    Help,
+}
+
+const SELECTION_FLAGS: &[&str] = &["--year", "--day"];
+const RUN_FLAGS: &[&str] = &[
+    "--year",
+    "--day",
+    "--part",
+    "--input",
+    "--input-str",
+    "--lenient",
+    "--format",
+];
+const BENCH_FLAGS: &[&str] = &[
+    "--year",
+    "--day",
+    "--iterations",
+    "--warmup",
+    "--save",
+    "--compare",
+    "--threshold",
+];
+
+pub fn parse_args<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+
+    let command = match args.next() {
+        None => return Ok(Command::Help),
+        Some(command) => command,
+    };
+    let allowed = match command.as_str() {
+        "run" => RUN_FLAGS,
+        "verify" | "fixtures" => SELECTION_FLAGS,
+        "bench" => BENCH_FLAGS,
+        "help" | "-h" | "--help" => return Ok(Command::Help),
+        other => return Err(format!("unknown command '{}'", other)),
+    };
+
+    let mut run = RunArgs::default();
+    let mut bench = BenchArgs::default();
+
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            return Ok(Command::Help);
+        }
+
+        // Accept both `--flag value` and `--flag=value`
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let known = [RUN_FLAGS, BENCH_FLAGS].iter().any(|flags| flags.contains(&flag.as_str()));
+        if !known {
+            return Err(format!("unknown option '{}'", flag));
+        }
+        if !allowed.contains(&flag.as_str()) {
+            return Err(format!("{} does not accept {}", command, flag));
+        }
+        if flag == "--lenient" {
+            run.mode = Mode::Lenient;
+            continue;
+        }
+
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("missing value for {}", flag))
+        };
+
+        match flag.as_str() {
+            "--year" => run.year = Some(parse_number(&flag, &value()?)?),
+            "--day" => {
+                let day = parse_number(&flag, &value()?)?;
+                if !(1..=25).contains(&day) {
+                    return Err(format!("day must be between 1 and 25, got {}", day));
+                }
+                run.day = Some(day);
+            }
+            "--part" => {
+                run.part = Some(match value()?.as_str() {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    other => return Err(format!("part must be 1 or 2, got '{}'", other)),
+                })
+            }
+            "--input" | "--input-str" => {
+                if run.input.is_some() {
+                    return Err("only one of --input and --input-str may be given".to_string());
+                }
+                let value = value()?;
+                run.input = Some(match flag.as_str() {
+                    "--input" => InputSource::from_arg(&value),
+                    _ => InputSource::Inline(value),
+                });
+            }
+            "--format" => run.format = value()?.parse()?,
+            "--iterations" => {
+                bench.iterations = parse_number(&flag, &value()?)?;
+                if bench.iterations == 0 {
+                    return Err("--iterations must be at least 1".to_string());
+                }
+            }
+            "--warmup" => bench.warmup = parse_number(&flag, &value()?)?,
+            "--save" => bench.save = Some(PathBuf::from(value()?)),
+            "--compare" => bench.compare = Some(PathBuf::from(value()?)),
+            "--threshold" => {
+                bench.threshold = parse_number(&flag, &value()?)?;
+                if bench.threshold.is_nan() || bench.threshold < 0.0 {
+                    return Err("--threshold must not be negative".to_string());
+                }
+            }
+            _ => unreachable!("unhandled option {}", flag),
+        }
+    }
+
+    let selection = Selection {
+        year: run.year,
+        day: run.day,
+    };
+    match command.as_str() {
+        "verify" => Ok(Command::Verify(selection)),
+        "fixtures" => Ok(Command::Fixtures(selection)),
+        "bench" => Ok(Command::Bench(BenchArgs {
+            year: run.year,
+            day: run.day,
+            ..bench
+        })),
+        _ => {
+            if run.input.is_some() && run.day.is_none() {
+                return Err("--input and --input-str require --day".to_string());
+            }
+            Ok(Command::Run(run))
+        }
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for {}", value, flag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_run_single_part() {
+        let command = parse(&["run", "--year", "2025", "--day", "7", "--part", "2", "--input", "in.txt"]);
+        assert_eq!(
+            command,
+            Ok(Command::Run(RunArgs {
+                year: Some(2025),
+                day: Some(7),
+                part: Some(Part::Two),
+                input: Some(InputSource::File(PathBuf::from("in.txt"))),
+                mode: Mode::Strict,
+                format: Format::Text,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_run_whole_year() {
+        let command = parse(&["run", "--year=2025"]);
+        assert_eq!(
+            command,
+            Ok(Command::Run(RunArgs {
+                year: Some(2025),
+                ..RunArgs::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_run_lenient() {
+        let command = parse(&["run", "--day", "8", "--lenient"]);
+        assert_eq!(
+            command,
+            Ok(Command::Run(RunArgs {
+                day: Some(8),
+                mode: Mode::Lenient,
+                ..RunArgs::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_run_json() {
+        let command = parse(&["run", "--format", "json"]);
+        assert!(matches!(command, Ok(Command::Run(RunArgs { format: Format::Json, .. }))));
+        assert!(parse(&["run", "--format=xml"]).is_err());
+    }
+
+    #[test]
+    fn test_run_stdin_and_inline() {
+        let stdin = parse(&["run", "--day", "1", "--input", "-"]);
+        assert!(matches!(stdin, Ok(Command::Run(RunArgs { input: Some(InputSource::Stdin), .. }))));
+
+        let inline = parse(&["run", "--day", "2", "--input-str", "11-22"]);
+        assert!(matches!(
+            inline,
+            Ok(Command::Run(RunArgs { input: Some(InputSource::Inline(text)), .. })) if text == "11-22"
+        ));
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(parse(&["verify"]), Ok(Command::Verify(Selection::default())));
+        assert_eq!(
+            parse(&["verify", "--year", "2025", "--day", "9"]),
+            Ok(Command::Verify(Selection {
+                year: Some(2025),
+                day: Some(9),
+            }))
+        );
+        assert!(parse(&["verify", "--part", "1"]).is_err());
+        assert!(parse(&["verify", "--day", "1", "--input", "in.txt"]).is_err());
+        assert_eq!(
+            parse(&["fixtures", "--day=4"]),
+            Ok(Command::Fixtures(Selection {
+                year: None,
+                day: Some(4),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_bench() {
+        assert_eq!(parse(&["bench"]), Ok(Command::Bench(BenchArgs::default())));
+        assert_eq!(
+            parse(&["bench", "--day", "9", "--iterations=5", "--warmup", "0", "--compare", "base.json"]),
+            Ok(Command::Bench(BenchArgs {
+                day: Some(9),
+                iterations: 5,
+                warmup: 0,
+                compare: Some(PathBuf::from("base.json")),
+                ..BenchArgs::default()
+            }))
+        );
+        assert!(parse(&["bench", "--iterations", "0"]).is_err());
+        assert!(parse(&["bench", "--threshold", "-5"]).is_err());
+        assert!(parse(&["bench", "--part", "1"]).is_err());
+        assert!(parse(&["run", "--save", "base.json"]).is_err());
+    }
+
+    #[test]
+    fn test_help() {
+        assert_eq!(parse(&[]), Ok(Command::Help));
+        assert_eq!(parse(&["run", "--help"]), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse(&["solve"]).is_err());
+        assert!(parse(&["run", "--day"]).is_err());
+        assert!(parse(&["run", "--day", "26"]).is_err());
+        assert!(parse(&["run", "--part", "3"]).is_err());
+        assert!(parse(&["run", "--year", "twenty"]).is_err());
+        assert!(parse(&["run", "--input", "in.txt"]).is_err());
+        assert!(parse(&["run", "--day", "1", "--input", "a.txt", "--input-str", "L1"]).is_err());
+        assert!(parse(&["run", "--verbose"]).is_err());
+    }
+}
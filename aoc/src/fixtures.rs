@@ -0,0 +1,68 @@
+// `aoc fixtures`: writes each day's README examples to `Day-DD/fixtures/` and
+// records their stated answers in `<year>/answers.toml`.
+
+use crate::answers::{self, Answers};
+use crate::cli::Selection;
+use crate::readme;
+use crate::registry::{self, Day};
+use std::fs;
+use std::path::Path;
+
+pub fn extract(args: Selection) -> Result<(), String> {
+    for day in registry::select(args.year, args.day)? {
+        extract_day(day)?;
+    }
+    Ok(())
+}
+
+fn extract_day(day: &Day) -> Result<(), String> {
+    // Fixtures belong to the day's crate, so always write into the workspace
+    let root = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let show = |path: &Path| path.strip_prefix(root).unwrap_or(path).display().to_string();
+    let year_dir = root.join(day.year.to_string());
+    let day_dir = year_dir.join(format!("Day-{:02}", day.day));
+
+    let readme_path = day_dir.join("README.md");
+    let readme = fs::read_to_string(&readme_path)
+        .map_err(|err| format!("failed to read {}: {}", readme_path.display(), err))?;
+    let extracted = readme::extract(&readme);
+    if extracted.examples.is_empty() {
+        println!("{} Day {:02}: no examples found", day.year, day.day);
+        return Ok(());
+    }
+
+    let fixtures_dir = day_dir.join("fixtures");
+    fs::create_dir_all(&fixtures_dir)
+        .map_err(|err| format!("failed to create {}: {}", fixtures_dir.display(), err))?;
+    for example in &extracted.examples {
+        let path = fixtures_dir.join(format!("{}.txt", example.name));
+        if fs::read_to_string(&path).is_ok_and(|existing| existing == example.input) {
+            continue;
+        }
+        fs::write(&path, &example.input)
+            .map_err(|err| format!("failed to write {}: {}", path.display(), err))?;
+        println!("{} Day {:02}: wrote {}", day.year, day.day, show(&path));
+    }
+
+    // Existing tables are left alone so hand-made corrections survive
+    let answers_path = year_dir.join(answers::FILE_NAME);
+    let recorded = Answers::load(&answers_path)?;
+    let name = &extracted.examples[0].name;
+    if recorded.inputs(day.day).contains(&name.as_str()) || extracted.answers.iter().all(Option::is_none) {
+        return Ok(());
+    }
+
+    let mut table = format!("\n[day{:02}.{}]\n", day.day, name);
+    for (part, answer) in extracted.answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            table += &format!("part{} = {}\n", part + 1, answer);
+        }
+    }
+    let mut text = fs::read_to_string(&answers_path).unwrap_or_default();
+    text += &table;
+    fs::write(&answers_path, text)
+        .map_err(|err| format!("failed to write {}: {}", answers_path.display(), err))?;
+    println!("{} Day {:02}: added {} answers to {}", day.year, day.day, name, show(&answers_path));
+
+    Ok(())
+}
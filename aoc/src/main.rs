@@ -0,0 +1,84 @@
+mod answers;
+mod bench;
+mod cli;
+mod fixtures;
+mod readme;
+mod registry;
+mod verify;
+
+use aoc_core::cli::Format;
+use aoc_core::source::{self, InputSource};
+use aoc_core::Part;
+use cli::{Command, RunArgs};
+use std::env;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let result = match cli::parse_args(env::args().skip(1)) {
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Verify(args)) => verify::verify(args),
+        Ok(Command::Fixtures(args)) => fixtures::extract(args),
+        Ok(Command::Bench(args)) => bench::bench(args),
+        Err(message) => Err(format!("{}\n\n{}", message, cli::USAGE)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let days = registry::select(args.year, args.day)?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    for day in days {
+        // AOC_INPUT names a single input, so it only applies to single-day runs
+        let source = match args.day {
+            Some(_) => InputSource::resolve(args.input.clone(), day.year, day.day),
+            None => InputSource::File(source::default_path(day.year, day.day)),
+        };
+        let input = source
+            .read()
+            .map_err(|err| format!("failed to read {}: {}", source, err))?;
+
+        let outcome = (day.solve)(&input, &parts, args.mode).map_err(|err| err.diagnostic())?;
+        if !outcome.skipped.is_empty() {
+            eprintln!(
+                "warning: skipped {} line(s) of {} day {} input",
+                outcome.skipped.len(),
+                day.year,
+                day.day
+            );
+            for err in &outcome.skipped {
+                eprintln!("  line {}: {:?}: {}", err.line, err.text, err.message);
+            }
+        }
+
+        match args.format {
+            Format::Text => {
+                for (part, answer) in parts.iter().zip(&outcome.answers) {
+                    println!("{} Day {:02} Part {}: {}", day.year, day.day, part, answer);
+                }
+            }
+            Format::Json => {
+                for line in outcome.to_json(day.year, day.day, &parts) {
+                    println!("{}", line);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+// Pulls the worked examples out of a day's README.md.
+//
+// The puzzle text lives under headings like `--- Day 4: ... ---`, `Part 1`
+// and `--- Part Two ---`; anything under other headings is notes. Within the
+// puzzle text, every plain ``` block introduced by a line mentioning
+// "example" is an example input. The first is named `example`, later ones
+// `example-2`, `example-3`, ... and repeats of an earlier block are skipped.
+//
+// A part's stated answer is the last number of the last sentence before the
+// part's question that talks about the example or a total.
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Extracted {
+    pub examples: Vec<Example>,
+    // Stated answers to parts 1 and 2 for the first example
+    pub answers: [Option<String>; 2],
+}
+
+#[derive(Default)]
+struct Section {
+    part: usize,
+    // Answer sentences seen since the last question
+    candidate: Option<String>,
+    answer: Option<String>,
+}
+
+pub fn extract(readme: &str) -> Extracted {
+    let mut extracted = Extracted::default();
+    let mut section: Option<Section> = None;
+    let mut previous = "";
+    let mut lines = readme.lines();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            let block: Vec<&str> = lines.by_ref().take_while(|l| !l.starts_with("```")).collect();
+            let is_example = line.trim() == "```" && previous.to_lowercase().contains("example");
+            if section.is_some() && is_example && !block.is_empty() {
+                add_example(&mut extracted.examples, block.join("\n") + "\n");
+            }
+            previous = "";
+            continue;
+        }
+
+        if let Some(heading) = line.strip_prefix('#') {
+            finish(&mut extracted, section.take());
+            section = puzzle_part(heading.trim_start_matches('#').trim()).map(|part| Section {
+                part,
+                ..Section::default()
+            });
+        } else if let Some(section) = section.as_mut() {
+            scan_line(section, line);
+        }
+
+        if !line.trim().is_empty() {
+            previous = line;
+        }
+    }
+    finish(&mut extracted, section);
+
+    extracted
+}
+
+// Which part a heading starts, if it's puzzle text at all.
+fn puzzle_part(heading: &str) -> Option<usize> {
+    if heading.contains("Part Two") || heading == "Part 2" {
+        Some(2)
+    } else if heading.contains("--- Day ") || heading == "Part 1" {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+fn add_example(examples: &mut Vec<Example>, input: String) {
+    if examples.iter().any(|example| example.input == input) {
+        return;
+    }
+    let name = match examples.len() {
+        0 => "example".to_string(),
+        n => format!("example-{}", n + 1),
+    };
+    examples.push(Example { name, input });
+}
+
+fn scan_line(section: &mut Section, line: &str) {
+    let line = line.trim();
+    if line.ends_with('?') {
+        // Only the sentence right before the question states its answer
+        if section.candidate.is_some() {
+            section.answer = section.candidate.take();
+        }
+        return;
+    }
+
+    for sentence in line.split(". ") {
+        let lower = sentence.to_lowercase();
+        let mentions_answer = ["example", "total", "produces"].iter().any(|word| lower.contains(word));
+        if let Some(number) = last_number(sentence).filter(|_| mentions_answer) {
+            section.candidate = Some(number);
+        }
+    }
+}
+
+fn finish(extracted: &mut Extracted, section: Option<Section>) {
+    if let Some(Section { part, answer: Some(answer), .. }) = section {
+        extracted.answers[part - 1] = Some(answer);
+    }
+}
+
+fn last_number(sentence: &str) -> Option<String> {
+    sentence
+        .split(|c: char| !c.is_ascii_digit())
+        .rfind(|digits| !digits.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "\
+# --- Day 1: Test ---
+
+For example:
+
+```
+1
+2
+```
+
+In this example, the total is 3. Other text.
+
+What is the total?
+
+# Answer (Part 1)
+
+For example:
+
+```
+not puzzle text
+```
+
+# --- Part Two ---
+
+Consider the example again:
+
+```
+1
+2
+```
+
+In this example with the extra marks:
+
+```
+1x
+2
+```
+
+The total is now 1 + 2 = 30.
+
+Be careful: 50!
+
+What is the new total?
+";
+
+    #[test]
+    fn test_extract() {
+        let extracted = extract(README);
+
+        assert_eq!(
+            extracted.examples,
+            vec![
+                Example {
+                    name: "example".to_string(),
+                    input: "1\n2\n".to_string(),
+                },
+                Example {
+                    name: "example-2".to_string(),
+                    input: "1x\n2\n".to_string(),
+                },
+            ]
+        );
+        assert_eq!(extracted.answers, [Some("3".to_string()), Some("30".to_string())]);
+    }
+
+    #[test]
+    fn test_no_examples() {
+        assert_eq!(extract("# Notes\n\n```\n1\n```\n"), Extracted::default());
+    }
+}
@@ -0,0 +1,91 @@
+// Every solver linked into the runner, keyed by year and day.
+
+use aoc_core::parse::{Mode, ParseError};
+use aoc_core::solution::{self, Outcome, Part, Solution};
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    // Parses the input once and solves the requested parts
+    pub solve: fn(&str, &[Part], Mode) -> Result<Outcome, ParseError>,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        year: S::YEAR,
+        day: S::DAY,
+        solve: solution::run::<S>,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day_01::Day01>(),
+    day::<day_02::Day02>(),
+    day::<day_03::Day03>(),
+    day::<day_04::Day04>(),
+    day::<day_05::Day05>(),
+    day::<day_06::Day06>(),
+    day::<day_07::Day07>(),
+    day::<day_08::Day08>(),
+    day::<day_09::Day09>(),
+];
+
+pub fn latest_year() -> Option<u16> {
+    DAYS.iter().map(|day| day.year).max()
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn days_in(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
+
+/// One day, or every day of the year; the year defaults to the latest.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    let year = match year {
+        Some(year) => year,
+        None => latest_year().ok_or("no solutions are registered")?,
+    };
+
+    let days: Vec<&Day> = match day {
+        Some(day) => {
+            vec![find(year, day).ok_or_else(|| format!("no solution registered for {} day {}", year, day))?]
+        }
+        None => days_in(year).collect(),
+    };
+    if days.is_empty() {
+        return Err(format!("no solutions registered for {}", year));
+    }
+
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_unique_and_sorted() {
+        for pair in DAYS.windows(2) {
+            assert!((pair[0].year, pair[0].day) < (pair[1].year, pair[1].day));
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2025, 7).map(|d| d.day), Some(7));
+        assert!(find(2025, 25).is_none());
+        assert!(find(2015, 1).is_none());
+        assert_eq!(days_in(2025).count(), 9);
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(select(Some(2025), None).unwrap().len(), 9);
+        assert_eq!(select(None, Some(3)).unwrap()[0].day, 3);
+        assert!(select(Some(2025), Some(25)).is_err());
+        assert!(select(Some(2015), None).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+// `aoc verify`: re-runs every day against its registered inputs and compares
+// the answers with `<year>/answers.toml`.
+
+use crate::answers::{self, Answers};
+use crate::cli::Selection;
+use crate::registry::{self, Day};
+use aoc_core::parse::Mode;
+use aoc_core::source;
+use aoc_core::Part;
+use std::fs;
+
+#[derive(Debug, Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+pub fn verify(args: Selection) -> Result<(), String> {
+    let days = registry::select(args.year, args.day)?;
+    let mut tally = Tally::default();
+
+    let mut loaded: Option<(u16, Answers)> = None;
+    for day in days {
+        if loaded.as_ref().is_none_or(|(year, _)| *year != day.year) {
+            let path = source::year_dir(day.year).join(answers::FILE_NAME);
+            loaded = Some((day.year, Answers::load(&path)?));
+        }
+        let (_, answers) = loaded.as_ref().unwrap();
+        verify_day(day, answers, &mut tally);
+    }
+
+    println!(
+        "{} passed, {} failed, {} missing",
+        tally.passed, tally.failed, tally.missing
+    );
+    if tally.failed > 0 {
+        return Err(format!("{} check(s) failed", tally.failed));
+    }
+    Ok(())
+}
+
+fn verify_day(day: &Day, answers: &Answers, tally: &mut Tally) {
+    // Every input with recorded answers, plus the puzzle input itself so a
+    // day without answers shows up as missing
+    let mut inputs = answers.inputs(day.day);
+    if !inputs.contains(&"input") && source::default_path(day.year, day.day).exists() {
+        inputs.push("input");
+        inputs.sort();
+    }
+    if inputs.is_empty() {
+        println!("{} Day {:02}: missing (no inputs or answers)", day.year, day.day);
+        tally.missing += 1;
+        return;
+    }
+
+    for name in inputs {
+        let label = format!("{} Day {:02} {:<12}", day.year, day.day, name);
+        let path = source::input_path(day.year, day.day, name);
+        let Ok(input) = fs::read_to_string(&path) else {
+            println!("{}: missing input file {}", label, path.display());
+            tally.missing += 1;
+            continue;
+        };
+
+        let outcome = match (day.solve)(&input, &Part::BOTH, Mode::Strict) {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                println!("{}: FAIL\n{}", label, err.diagnostic());
+                tally.failed += 1;
+                continue;
+            }
+        };
+
+        for (part, answer) in Part::BOTH.into_iter().zip(outcome.answers) {
+            let answer = answer.to_string();
+            match answers.get(day.day, name, part) {
+                Some(expected) if expected == answer => {
+                    println!("{} Part {}: pass ({})", label, part, answer);
+                    tally.passed += 1;
+                }
+                Some(expected) => {
+                    println!("{} Part {}: FAIL (got {}, expected {})", label, part, answer, expected);
+                    tally.failed += 1;
+                }
+                None => {
+                    println!("{} Part {}: missing (got {})", label, part, answer);
+                    tally.missing += 1;
+                }
+            }
+        }
+    }
+}